@@ -1,29 +1,152 @@
-use regex::Regex;
-use std::ops::Range;
 use checkluhn;
 
-lazy_static! {
+/// Issuer Identification Number ranges: first & last prefix (of the same
+/// length) and the brand. A card number belongs to the brand of its longest
+/// matching prefix, eg: 4026 is Visa Electron rather than Visa; on ties the
+/// first entry wins.
+///
+/// 622126-622925 are UnionPay cards also accepted on the Discover network,
+/// they're reported as UnionPay. Cartes Bancaires has no ranges of its own:
+/// its cards are co-badged within Visa & MasterCard ranges, see `CB_IIN_RANGES`.
+const IIN_RANGES: &[(u32, u32, Type)] = &[
     // Debit Cards
-    static ref VISAELECTRON: Regex = Regex::new(r"^4(026|17500|405|508|844|91[37])").unwrap();
-    static ref MAESTRO: Regex = Regex::new(r"^(5(018|0[23]|[68])|6(39|7))").unwrap();
-    static ref FORBRUGSFORENINGEN: Regex = Regex::new(r"^600").unwrap();
-    static ref DANKORT: Regex = Regex::new(r"^5019").unwrap();
+    (4026, 4026, Type::VisaElectron),
+    (417500, 417500, Type::VisaElectron),
+    (4405, 4405, Type::VisaElectron),
+    (4508, 4508, Type::VisaElectron),
+    (4844, 4844, Type::VisaElectron),
+    (4913, 4913, Type::VisaElectron),
+    (4917, 4917, Type::VisaElectron),
+    (5018, 5018, Type::Maestro),
+    (5020, 5020, Type::Maestro),
+    (5038, 5038, Type::Maestro),
+    (56, 58, Type::Maestro),
+    (6304, 6304, Type::Maestro),
+    (67, 67, Type::Maestro),
+    (600, 600, Type::Forbrugsforeningen),
+    (5019, 5019, Type::Dankort),
+    (4571, 4571, Type::Dankort),
+    (508500, 508999, Type::RuPay),
+    (606985, 607984, Type::RuPay),
+    (608001, 608500, Type::RuPay),
+    (652150, 653149, Type::RuPay),
+    (506099, 506198, Type::Verve),
+    (507865, 507964, Type::Verve),
+    (650002, 650027, Type::Verve),
 
     // Credit Cards
-    static ref VISA: Regex = Regex::new(r"^4").unwrap();
-    static ref MASTERCARD: Regex = Regex::new(r"^(5[1-5]|2[2-7])").unwrap();
-    static ref AMEX: Regex = Regex::new(r"^3[47]").unwrap();
-    static ref DINERSCLUB: Regex = Regex::new(r"^3[0689]").unwrap();
-    static ref DISCOVER: Regex = Regex::new(r"^6([045]|22)").unwrap();
-    static ref UNIONPAY: Regex = Regex::new(r"^(62|88)").unwrap();
-    static ref JCB: Regex = Regex::new(r"^35").unwrap();
+    (4, 4, Type::Visa),
+    (51, 55, Type::MasterCard),
+    (2221, 2720, Type::MasterCard),
+    (34, 34, Type::Amex),
+    (37, 37, Type::Amex),
+    (300, 305, Type::DinersClub),
+    (3095, 3095, Type::DinersClub),
+    (36, 36, Type::DinersClub),
+    (38, 39, Type::DinersClub),
+    (6011, 6011, Type::Discover),
+    (644, 649, Type::Discover),
+    (65, 65, Type::Discover),
+    (62, 62, Type::UnionPay),
+    (81, 81, Type::UnionPay),
+    (88, 88, Type::UnionPay),
+    (3528, 3589, Type::JCB),
+    (2200, 2204, Type::Mir),
+    (401178, 401179, Type::Elo),
+    (431274, 431274, Type::Elo),
+    (438935, 438935, Type::Elo),
+    (451416, 451416, Type::Elo),
+    (457393, 457393, Type::Elo),
+    (457631, 457632, Type::Elo),
+    (504175, 504175, Type::Elo),
+    (506699, 506778, Type::Elo),
+    (509000, 509999, Type::Elo),
+    (627780, 627780, Type::Elo),
+    (636297, 636297, Type::Elo),
+    (636368, 636368, Type::Elo),
+    (650031, 650051, Type::Elo),
+    (650405, 650439, Type::Elo),
+    (650485, 650538, Type::Elo),
+    (650541, 650598, Type::Elo),
+    (650700, 650727, Type::Elo),
+    (650901, 650978, Type::Elo),
+    (651652, 651679, Type::Elo),
+    (655000, 655058, Type::Elo),
+    (3841, 3841, Type::Hipercard),
+    (606282, 606282, Type::Hipercard),
+    (637095, 637095, Type::Hipercard),
+    (637568, 637568, Type::Hipercard),
+    (637599, 637612, Type::Hipercard),
+    (9792, 9792, Type::Troy),
+    (1, 1, Type::Uatp),
+    (6541, 6541, Type::BcCard),
+    (6556, 6556, Type::BcCard),
+    (636, 636, Type::InterPayment),
+    (637, 639, Type::InstaPayment),
+];
+
+/// IIN ranges of Visa & MasterCard cards co-badged with Cartes Bancaires,
+/// reported as their `co_brand` by `inspect_card`. CB doesn't publish its BIN
+/// list, so this only covers the 497 block of French issued Visa cards.
+const CB_IIN_RANGES: &[(u32, u32)] = &[(497, 497)];
+
+/// Name of the national scheme co-badged on Visa & MasterCard cards
+const CARTES_BANCAIRES: &str = "Cartes Bancaires";
+
+/// Why a card number is invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardError {
+    /// Nothing but separators
+    Empty,
+    /// Characters other than digits, spaces & dashes
+    InvalidCharacters,
+    /// The number isn't in any brand's IIN ranges
+    UnknownBrand,
+    /// The length isn't valid for the brand
+    Length,
+    /// The luhn check digit doesn't match
+    Luhn,
+}
+
+/// Result of inspecting a card number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardInfo {
+    /// Card number without separators
+    pub number: String,
+    /// Brand name, eg: `Visa`
+    pub brand: Option<&'static str>,
+    /// Co-badged national scheme, eg: `Cartes Bancaires`
+    pub co_brand: Option<&'static str>,
+    /// Issuer Identification Number, the first 6 digits
+    pub iin: Option<String>,
+    /// Last 4 digits
+    pub last4: Option<String>,
+    /// The length is one of the brand's allowed lengths
+    pub length_valid: bool,
+    /// The number passes the luhn check
+    pub luhn_valid: bool,
+    /// First reason the number is invalid, `None` for a valid card
+    pub error: Option<CardError>,
+}
+
+/// Which digits of a card number are left visible by `mask_card`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardMask {
+    /// The first 6 (IIN) and last 4 digits, eg: `411111******1111`.
+    /// Numbers under 16 digits show fewer leading digits: `370********4107`
+    FirstSixLastFour,
+    /// Only the last 4 digits, eg: `************1111`
+    LastFour,
 }
 
+#[derive(Clone, Copy, PartialEq)]
 enum Type {
     VisaElectron,
     Maestro,
     Forbrugsforeningen,
     Dankort,
+    RuPay,
+    Verve,
     Visa,
     MasterCard,
     Amex,
@@ -31,6 +154,14 @@ enum Type {
     Discover,
     UnionPay,
     JCB,
+    Mir,
+    Elo,
+    Hipercard,
+    Troy,
+    Uatp,
+    BcCard,
+    InterPayment,
+    InstaPayment,
 }
 
 impl Type {
@@ -40,6 +171,8 @@ impl Type {
             Type::Maestro => "Maestro",
             Type::Forbrugsforeningen => "Forbrugsforeningen",
             Type::Dankort => "Dankort",
+            Type::RuPay => "RuPay",
+            Type::Verve => "Verve",
             Type::Visa => "Visa",
             Type::MasterCard => "MasterCard",
             Type::Amex => "Amex",
@@ -47,67 +180,60 @@ impl Type {
             Type::Discover => "Discover",
             Type::UnionPay => "UnionPay",
             Type::JCB => "JCB",
+            Type::Mir => "Mir",
+            Type::Elo => "Elo",
+            Type::Hipercard => "Hipercard",
+            Type::Troy => "Troy",
+            Type::Uatp => "UATP",
+            Type::BcCard => "BC Card",
+            Type::InterPayment => "InterPayment",
+            Type::InstaPayment => "InstaPayment",
         }
     }
 
-    fn pattern<'a>(&self) -> &'a Regex {
+    /// Allowed card number lengths
+    fn lengths(&self) -> &'static [usize] {
         match *self {
-            Type::VisaElectron => &VISAELECTRON,
-            Type::Maestro => &MAESTRO,
-            Type::Forbrugsforeningen => &FORBRUGSFORENINGEN,
-            Type::Dankort => &DANKORT,
-            Type::Visa => &VISA,
-            Type::MasterCard => &MASTERCARD,
-            Type::Amex => &AMEX,
-            Type::DinersClub => &DINERSCLUB,
-            Type::Discover => &DISCOVER,
-            Type::UnionPay => &UNIONPAY,
-            Type::JCB => &JCB,
+            Type::VisaElectron => &[16],
+            Type::Maestro => &[12, 13, 14, 15, 16, 17, 18, 19],
+            Type::Forbrugsforeningen => &[16],
+            Type::Dankort => &[16],
+            Type::RuPay => &[16],
+            Type::Verve => &[16, 18, 19],
+            Type::Visa => &[13, 16, 19],
+            // both the 51-55 and 2221-2720 series are only issued as 16 digits
+            Type::MasterCard => &[16],
+            Type::Amex => &[15],
+            Type::DinersClub => &[14, 15, 16, 17, 18, 19],
+            Type::Discover => &[16, 17, 18, 19],
+            Type::JCB => &[16, 17, 18, 19],
+            Type::UnionPay => &[16, 17, 18, 19],
+            Type::Mir => &[16, 17, 18, 19],
+            Type::Elo => &[16],
+            Type::Hipercard => &[16, 19],
+            Type::Troy => &[16],
+            Type::Uatp => &[15],
+            Type::BcCard => &[16],
+            Type::InterPayment => &[16, 17, 18, 19],
+            Type::InstaPayment => &[16],
         }
     }
+}
 
-    fn length<'a>(&self) -> Range<usize> {
-        match *self {
-            Type::VisaElectron => Range { start: 16, end: 16 },
-            Type::Maestro => Range { start: 12, end: 19 },
-            Type::Forbrugsforeningen => Range { start: 16, end: 16 },
-            Type::Dankort => Range { start: 16, end: 16 },
-            Type::Visa => Range { start: 13, end: 16 },
-            Type::MasterCard => Range { start: 16, end: 16 },
-            Type::Amex => Range { start: 15, end: 15 },
-            Type::DinersClub => Range { start: 13, end: 16 },
-            Type::Discover => Range { start: 16, end: 16 },
-            Type::JCB => Range { start: 16, end: 16 },
-            Type::UnionPay => Range { start: 16, end: 19 },
-        }
-    }
-
-    fn all() -> Vec<Type> {
-        vec![
-            // Debit Cards
-            Type::VisaElectron,
-            Type::Maestro,
-            Type::Forbrugsforeningen,
-            Type::Dankort,
-
-            // Credit Cards
-            Type::Visa,
-            Type::MasterCard,
-            Type::Amex,
-            Type::DinersClub,
-            Type::Discover,
-            Type::UnionPay,
-            Type::JCB,
-        ]
+/// Digit group sizes a card number of the given length & brand is displayed with
+fn groups(length: usize, card: Option<Type>) -> Vec<usize> {
+    match (card, length) {
+        (Some(Type::Amex), 15) => vec![4, 6, 5],
+        (Some(Type::DinersClub), 14) => vec![4, 6, 4],
+        (_, 19) => vec![4, 4, 4, 4, 3],
+        // groups of 4, the remainder last
+        _ => (0..length).step_by(4).map(|x| (length - x).min(4)).collect(),
     }
 }
 
 /// Check if the given card number and card type has a valid length
 fn is_length_valid(card_number: &str, card_type: &Type) -> bool {
-    let size = card_number.len();
-    let range = card_type.length();
-
-    size >= range.start && size <= range.end
+    card_type.lengths().contains(&card_number.len())
 }
 
 /// Check if card number passes luhn test
@@ -115,26 +241,86 @@ fn is_luhn_valid(card_number: &str) -> bool {
     checkluhn::validate(&card_number)
 }
 
+/// Separators allowed between the digit groups of a card number
+const SEPARATORS: [char; 2] = [' ', '-'];
+
+/// Number of leading digits reported as the IIN
+const IIN_LENGTH: usize = 6;
+
+/// Shortest card number that can be masked, the shortest allowed length of any brand
+const MIN_MASK_LENGTH: usize = 12;
+
+/// Card numbers shorter than this show fewer than 6 leading digits when masked,
+/// so at least half of the number stays hidden
+const FULL_IIN_MASK_LENGTH: usize = 16;
+
+/// Remove the separators between the digit groups of a card number
+fn normalize(value: &str) -> String {
+    value.replace(SEPARATORS, "")
+}
+
+/// Determine the Card Type from the longest matching IIN range
+fn card_type(card_number: &str) -> Option<Type> {
+    if !card_number.chars().all(|x| x.is_ascii_digit()) {
+        return None
+    }
+    let mut card = None;
+    let mut longest = 0;
+    for (first, last, card_type) in IIN_RANGES.iter() {
+        let length = first.to_string().len();
+        let prefix = match card_number.get(..length) {
+            Some(x) => x.parse::<u32>().unwrap(),
+            None => continue,
+        };
+        if length > longest && (*first..=*last).contains(&prefix) {
+            card = Some(*card_type);
+            longest = length;
+        }
+    }
+    card
+}
+
+/// Co-badged national scheme of a Visa or MasterCard number
+fn co_brand(card_number: &str, card: Option<Type>) -> Option<&'static str> {
+    if card_number.len() < IIN_LENGTH || !matches!(card, Some(Type::Visa) | Some(Type::MasterCard)) {
+        return None
+    }
+    let cb = CB_IIN_RANGES.iter().any(|(first, last)| {
+        card_number
+            .get(..first.to_string().len())
+            .and_then(|x| x.parse::<u32>().ok())
+            .is_some_and(|x| (*first..=*last).contains(&x))
+    });
+    if cb {
+        Some(CARTES_BANCAIRES)
+    } else {
+        None
+    }
+}
+
+/// Validate the Card for its Brand
+fn validate_card(card_number: &str, card_type: &Type) -> bool {
+    // Check Length & if it passes luhn algorithm
+    is_length_valid(card_number, card_type) && is_luhn_valid(card_number)
+}
+
 /// Evaluate Card Type & Validate Card for its Brand
 fn validate(value: &str) -> bool {
-    // if card number (value) contains spaces in between, remove them
-    let value = value.replace(" ", "");
-    for card in Type::all() {
-        if card.pattern().is_match(&value) {
-            // Check Length
-            if is_length_valid(&value, &card) {
-                // Check if it passes luhn algorithm
-                if is_luhn_valid(&value) {
-                    return true
-                }
-                // luhn check failed
-                return false
-            }
-            // card type length check failed
-            return false
-        }
+    // if card number (value) contains spaces or dashes in between, remove them
+    let value = normalize(value);
+    match card_type(&value) {
+        Some(card) => validate_card(&value, &card),
+        None => false,
+    }
+}
+
+/// Validate the Card against a single Card Type
+fn validate_type(value: &str, card: Type) -> bool {
+    let value = normalize(value);
+    match card_type(&value) {
+        Some(x) if x == card => validate_card(&value, &card),
+        _ => false,
     }
-    false
 }
 
 pub fn is_valid_visa_electron(value: &str) -> bool {
@@ -148,7 +334,7 @@ pub fn is_valid_visa_electron(value: &str) -> bool {
     //!     assert_eq!(is_valid_visa_electron("4844 1614 5954 6174"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::VisaElectron)
 }
 
 pub fn is_valid_maestro(value: &str) -> bool {
@@ -162,7 +348,7 @@ pub fn is_valid_maestro(value: &str) -> bool {
     //!     assert_eq!(is_valid_maestro("5898009041197"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Maestro)
 }
 
 pub fn is_valid_forbrugsforeningen(value: &str) -> bool {
@@ -176,7 +362,7 @@ pub fn is_valid_forbrugsforeningen(value: &str) -> bool {
     //! assert_eq!(is_valid_forbrugsforeningen("6007221111111110"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Forbrugsforeningen)
 }
 
 pub fn is_valid_dankort(value: &str) -> bool {
@@ -190,7 +376,7 @@ pub fn is_valid_dankort(value: &str) -> bool {
     //! assert_eq!(is_valid_dankort("5019118545073189"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Dankort)
 }
 
 pub fn is_valid_visa(value: &str) -> bool {
@@ -204,7 +390,7 @@ pub fn is_valid_visa(value: &str) -> bool {
     //! assert_eq!(is_valid_visa("4035 3005 3980 4083"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Visa)
 }
 
 pub fn is_valid_mastercard(value: &str) -> bool {
@@ -218,7 +404,7 @@ pub fn is_valid_mastercard(value: &str) -> bool {
     //! assert_eq!(is_valid_mastercard("5463 1135 8998 2388"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::MasterCard)
 }
 
 pub fn is_valid_amex(value: &str) -> bool {
@@ -232,7 +418,7 @@ pub fn is_valid_amex(value: &str) -> bool {
     //! assert_eq!(is_valid_amex("3707 897090 84107"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Amex)
 }
 
 pub fn is_valid_diners_club(value: &str) -> bool {
@@ -246,7 +432,7 @@ pub fn is_valid_diners_club(value: &str) -> bool {
     //! assert_eq!(is_valid_diners_club("3022143741431999"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::DinersClub)
 }
 
 pub fn is_valid_discover(value: &str) -> bool {
@@ -260,7 +446,7 @@ pub fn is_valid_discover(value: &str) -> bool {
     //! assert_eq!(is_valid_discover("6011575126600688"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::Discover)
 }
 
 pub fn is_valid_unionpay(value: &str) -> bool {
@@ -274,7 +460,7 @@ pub fn is_valid_unionpay(value: &str) -> bool {
     //! assert_eq!(is_valid_unionpay("62600094752489242"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::UnionPay)
 }
 
 pub fn is_valid_jcb(value: &str) -> bool {
@@ -288,7 +474,147 @@ pub fn is_valid_jcb(value: &str) -> bool {
     //! assert_eq!(is_valid_jcb("3588337499926343"), true)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::JCB)
+}
+
+pub fn is_valid_mir(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is Mir
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_mir;
+    //! fn main() {
+    //! assert_eq!(is_valid_mir("2200 1234 5677 7774"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Mir)
+}
+
+pub fn is_valid_rupay(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is RuPay
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_rupay;
+    //! fn main() {
+    //! assert_eq!(is_valid_rupay("6070 1234 5677 7772"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::RuPay)
+}
+
+pub fn is_valid_elo(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is Elo
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_elo;
+    //! fn main() {
+    //! assert_eq!(is_valid_elo("5090 1234 5677 7770"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Elo)
+}
+
+pub fn is_valid_hipercard(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is Hipercard
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_hipercard;
+    //! fn main() {
+    //! assert_eq!(is_valid_hipercard("6062 8212 3477 7770"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Hipercard)
+}
+
+pub fn is_valid_troy(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is Troy
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_troy;
+    //! fn main() {
+    //! assert_eq!(is_valid_troy("9792 1234 5677 7773"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Troy)
+}
+
+pub fn is_valid_verve(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is Verve
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_verve;
+    //! fn main() {
+    //! assert_eq!(is_valid_verve("5061 0012 3477 7772"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Verve)
+}
+
+pub fn is_valid_uatp(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is UATP
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_uatp;
+    //! fn main() {
+    //! assert_eq!(is_valid_uatp("1354 12345 677772"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::Uatp)
+}
+
+pub fn is_valid_bc_card(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is BC Card
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_bc_card;
+    //! fn main() {
+    //! assert_eq!(is_valid_bc_card("6541 1234 5677 7773"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::BcCard)
+}
+
+pub fn is_valid_interpayment(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is InterPayment
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_interpayment;
+    //! fn main() {
+    //! assert_eq!(is_valid_interpayment("6361 2345 6777 7771"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::InterPayment)
+}
+
+pub fn is_valid_instapayment(value: &str) -> bool {
+    //! Check if the Given Credit/Debit card number is InstaPayment
+    //! and that if its valid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::is_valid_instapayment;
+    //! fn main() {
+    //! assert_eq!(is_valid_instapayment("6381 2345 6777 7777"), true)
+    //! }
+    //! ```
+    validate_type(value, Type::InstaPayment)
 }
 
 pub fn is_card_any(value: &str) -> bool {
@@ -315,14 +641,139 @@ pub fn which_card(value: &str) -> Option<&str> {
     //!     println!("{:?}", which_card("3707 897090 84107"));
     //! }
     //! ```
-    // remove the spaces if the card number contains
-    let value = value.replace(" ", "");
-    for card in Type::all() {
-        if card.pattern().is_match(&value) {
-            return Some(card.name())
+    // remove the spaces or dashes if the card number contains
+    let value = normalize(value);
+    card_type(&value).map(|card| card.name())
+}
+
+pub fn inspect_card(value: &str) -> CardInfo {
+    //! Inspect a Credit/Debit card number: the brand and co-badged scheme
+    //! (Cartes Bancaires), IIN & last 4 digits, whether its length is valid
+    //! for the brand and if it passes the luhn check, along with the first
+    //! reason it's invalid.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::{inspect_card, CardError};
+    //!
+    //! fn main() {
+    //!     let card = inspect_card("4035 3005 3980 4082");
+    //!     assert_eq!(card.brand, Some("Visa"));
+    //!     assert_eq!(card.last4.as_deref(), Some("4082"));
+    //!     assert!(card.length_valid);
+    //!     assert_eq!(card.error, Some(CardError::Luhn));
+    //!     assert_eq!(card.co_brand, None);
+    //!     assert_eq!(inspect_card("4970 1000 0000 0006").co_brand, Some("Cartes Bancaires"));
+    //! }
+    //! ```
+    let number = normalize(value);
+    let digits = !number.is_empty() && number.chars().all(|x| x.is_ascii_digit());
+    let card = card_type(&number);
+    let length_valid = card.is_some_and(|x| is_length_valid(&number, &x));
+    let luhn_valid = digits && is_luhn_valid(&number);
+    let error = if number.is_empty() {
+        Some(CardError::Empty)
+    } else if !digits {
+        Some(CardError::InvalidCharacters)
+    } else if card.is_none() {
+        Some(CardError::UnknownBrand)
+    } else if !length_valid {
+        Some(CardError::Length)
+    } else if !luhn_valid {
+        Some(CardError::Luhn)
+    } else {
+        None
+    };
+    CardInfo {
+        iin: number.get(..IIN_LENGTH).filter(|_| digits).map(String::from),
+        last4: number.get(number.len().saturating_sub(4)..).filter(|x| digits && x.len() == 4).map(String::from),
+        brand: card.map(|x| x.name()),
+        co_brand: co_brand(&number, card),
+        number,
+        length_valid,
+        luhn_valid,
+        error,
+    }
+}
+
+pub fn format_card(value: &str, separator: char) -> Option<String> {
+    //! Format a Credit/Debit card number in the digit groups of its brand:
+    //! 4-4-4-4, Amex 4-6-5, Diners Club 4-6-4 and 4-4-4-4-3 for 19 digits.
+    //! Spaces or dashes in the given value are replaced.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::format_card;
+    //!
+    //! fn main() {
+    //!     assert_eq!(format_card("370789709084107", ' ').as_deref(), Some("3707 897090 84107"));
+    //!     assert_eq!(format_card("4035-3005-3980-4083", ' ').as_deref(), Some("4035 3005 3980 4083"));
+    //!     assert_eq!(format_card("4035 300x", ' '), None);
+    //! }
+    //! ```
+    let number = normalize(value);
+    if number.is_empty() || !number.chars().all(|x| x.is_ascii_digit()) {
+        return None
+    }
+    let mut formatted = String::with_capacity(number.len() + 4);
+    let mut start = 0;
+    for size in groups(number.len(), card_type(&number)) {
+        if start > 0 {
+            formatted.push(separator);
         }
+        formatted.push_str(&number[start..start + size]);
+        start += size;
     }
-    None
+    Some(formatted)
+}
+
+pub fn mask_card(value: &str, masking: CardMask, mask: char) -> Option<String> {
+    //! Mask a Credit/Debit card number for display (PCI DSS), leaving the
+    //! first 6 & last 4 or only the last 4 digits visible. Numbers under 16
+    //! digits show fewer leading digits so at least half of them is masked.
+    //! Spaces or dashes stay where they were in the given value, format the
+    //! number with `format_card` first to regroup it by brand.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::creditcard::{format_card, mask_card, CardMask};
+    //!
+    //! fn main() {
+    //!     assert_eq!(
+    //!         mask_card("4035 3005 3980 4083", CardMask::FirstSixLastFour, '*').as_deref(),
+    //!         Some("4035 30** **** 4083")
+    //!     );
+    //!     let formatted = format_card("370789709084107", '-').unwrap();
+    //!     assert_eq!(mask_card(&formatted, CardMask::LastFour, '•').as_deref(), Some("••••-••••••-•4107"));
+    //! }
+    //! ```
+    let number = normalize(value);
+    if number.len() < MIN_MASK_LENGTH || !number.chars().all(|x| x.is_ascii_digit()) {
+        return None
+    }
+    let first = match masking {
+        CardMask::FirstSixLastFour if number.len() < FULL_IIN_MASK_LENGTH => number.len() / 2 - 4,
+        CardMask::FirstSixLastFour => IIN_LENGTH,
+        CardMask::LastFour => 0,
+    };
+    let last = number.len() - 4;
+    let mut digit = 0;
+    let masked = value
+        .trim()
+        .chars()
+        .map(|x| {
+            if SEPARATORS.contains(&x) {
+                return x
+            }
+            digit += 1;
+            if digit > first && digit <= last {
+                mask
+            } else {
+                x
+            }
+        })
+        .collect();
+    Some(masked)
 }
 
 #[cfg(test)]
@@ -330,6 +781,20 @@ mod tests {
     // Card numbers are generated from: https://debitcard-generator.com/validator
     use super::*;
 
+    /// A luhn valid card number of the given length starting with the prefix
+    fn card(prefix: &str, length: usize) -> String {
+        let mut card = format!("{:0<1$}", prefix, length - 1);
+        let sum: u32 = card
+            .chars()
+            .rev()
+            .zip([2, 1].iter().cycle())
+            .map(|(x, weight)| x.to_digit(10).unwrap() * weight)
+            .map(|x| if x > 9 { x - 9 } else { x })
+            .sum();
+        card.push(std::char::from_digit((10 - sum % 10) % 10, 10).unwrap());
+        card
+    }
+
     #[test]
     fn test_which_card() {
         // Visa Electron
@@ -354,6 +819,55 @@ mod tests {
         assert_eq!(which_card("62600094752489245"), Some("UnionPay"));
         // JCB
         assert_eq!(which_card("3588337499926343"), Some("JCB"));
+        // Mir
+        assert_eq!(which_card("2200123456777774"), Some("Mir"));
+        // RuPay
+        assert_eq!(which_card("6070123456777772"), Some("RuPay"));
+        // Elo
+        assert_eq!(which_card("5090123456777770"), Some("Elo"));
+        // Hipercard
+        assert_eq!(which_card("6062821234777770"), Some("Hipercard"));
+        // Troy
+        assert_eq!(which_card("9792123456777773"), Some("Troy"));
+        // Verve
+        assert_eq!(which_card("5061001234777772"), Some("Verve"));
+        // UATP
+        assert_eq!(which_card("135412345677772"), Some("UATP"));
+        // BC Card
+        assert_eq!(which_card("6541123456777773"), Some("BC Card"));
+        // InterPayment
+        assert_eq!(which_card("6361234567777771"), Some("InterPayment"));
+        // InstaPayment
+        assert_eq!(which_card("6381234567777777"), Some("InstaPayment"));
+    }
+
+    #[test]
+    fn test_which_card_longest_match() {
+        // 2-series MasterCard, but not Mir or past 2720
+        assert_eq!(which_card("2221001234777776"), Some("MasterCard"));
+        assert_eq!(which_card("2720991234777776"), Some("MasterCard"));
+        assert_eq!(which_card("2204991234777776"), Some("Mir"));
+        assert_eq!(which_card("2721001234777776"), None);
+        // UnionPay throughout 622, including the Discover co-branded range
+        assert_eq!(which_card("6221251234777777"), Some("UnionPay"));
+        assert_eq!(which_card("6221261234777777"), Some("UnionPay"));
+        assert_eq!(which_card("6229251234777777"), Some("UnionPay"));
+        assert_eq!(which_card(&card("81", 16)), Some("UnionPay"));
+        assert_eq!(which_card(&card("88", 19)), Some("UnionPay"));
+        assert_eq!(which_card("6500261234777777"), Some("Verve"));
+        assert_eq!(which_card("6500281234777777"), Some("Discover"));
+        // Visa, unless a longer prefix says otherwise
+        assert_eq!(which_card("4571123456777771"), Some("Dankort"));
+        assert_eq!(which_card("4011781234777777"), Some("Elo"));
+        assert_eq!(which_card("4011801234777777"), Some("Visa"));
+        // Diners Club only in its own 3x ranges
+        assert_eq!(which_card("3095123456777770"), Some("Diners Club"));
+        assert_eq!(which_card("3841001234777777"), Some("Hipercard"));
+        assert_eq!(which_card("3096123456777770"), None);
+        assert_eq!(which_card("3312345677777"), None);
+        // not a card number
+        assert_eq!(which_card("4a11123456777771"), None);
+        assert_eq!(which_card(""), None);
     }
 
     #[test]
@@ -409,7 +923,7 @@ mod tests {
     fn test_is_valid_diners_club() {
         assert!(is_valid_diners_club("3022143741431999"));
         assert!(!is_valid_diners_club("3022143741431990"));
-        assert!(is_valid_diners_club("3860847190349"));
+        assert!(!is_valid_diners_club("3860847190349"));
         assert!(is_valid_diners_club("30043277253245"));
     }
 
@@ -430,4 +944,174 @@ mod tests {
         assert!(is_valid_jcb("3588337499926343"));
         assert!(!is_valid_jcb("3588337499926345"))
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_is_valid_brand_only() {
+        // a valid card of each brand, against every brand function
+        let cards = [
+            "4844161459546174",
+            "6796265520244",
+            "6007221111111110",
+            "5019118545073189",
+            "4035300539804083",
+            "5463113589982388",
+            "370789709084107",
+            "3022143741431999",
+            "6011575126600688",
+            "62600094752489242",
+            "3588337499926343",
+            "2200123456777774",
+            "6070123456777772",
+            "5090123456777770",
+            "6062821234777770",
+            "9792123456777773",
+            "5061001234777772",
+            "135412345677772",
+            "6541123456777773",
+            "6361234567777771",
+            "6381234567777777",
+        ];
+        let brands: [fn(&str) -> bool; 21] = [
+            is_valid_visa_electron,
+            is_valid_maestro,
+            is_valid_forbrugsforeningen,
+            is_valid_dankort,
+            is_valid_visa,
+            is_valid_mastercard,
+            is_valid_amex,
+            is_valid_diners_club,
+            is_valid_discover,
+            is_valid_unionpay,
+            is_valid_jcb,
+            is_valid_mir,
+            is_valid_rupay,
+            is_valid_elo,
+            is_valid_hipercard,
+            is_valid_troy,
+            is_valid_verve,
+            is_valid_uatp,
+            is_valid_bc_card,
+            is_valid_interpayment,
+            is_valid_instapayment,
+        ];
+        for (i, card) in cards.iter().enumerate() {
+            assert!(is_card_any(card));
+            for (j, is_valid) in brands.iter().enumerate() {
+                assert_eq!(is_valid(card), i == j, "{} against brand {}", card, j);
+            }
+        }
+    }
+
+    /// Check every length from 11 to 20 digits against the allowed ones
+    fn assert_lengths(prefix: &str, is_valid: fn(&str) -> bool, lengths: &[usize]) {
+        for length in 11..=20 {
+            let card = card(prefix, length);
+            assert_eq!(is_valid(&card), lengths.contains(&length), "{} ({} digits)", card, length);
+        }
+    }
+
+    #[test]
+    fn test_card_lengths() {
+        assert_lengths("4026", is_valid_visa_electron, &[16]);
+        assert_lengths("6796", is_valid_maestro, &[12, 13, 14, 15, 16, 17, 18, 19]);
+        assert_lengths("600722", is_valid_forbrugsforeningen, &[16]);
+        assert_lengths("5019", is_valid_dankort, &[16]);
+        assert_lengths("4035", is_valid_visa, &[13, 16, 19]);
+        assert_lengths("5463", is_valid_mastercard, &[16]);
+        assert_lengths("37", is_valid_amex, &[15]);
+        assert_lengths("36", is_valid_diners_club, &[14, 15, 16, 17, 18, 19]);
+        assert_lengths("6011", is_valid_discover, &[16, 17, 18, 19]);
+        assert_lengths("626", is_valid_unionpay, &[16, 17, 18, 19]);
+        assert_lengths("3588", is_valid_jcb, &[16, 17, 18, 19]);
+        assert_lengths("2200", is_valid_mir, &[16, 17, 18, 19]);
+        assert_lengths("6070", is_valid_rupay, &[16]);
+        assert_lengths("5090", is_valid_elo, &[16]);
+        assert_lengths("3841", is_valid_hipercard, &[16, 19]);
+        assert_lengths("9792", is_valid_troy, &[16]);
+        assert_lengths("5061", is_valid_verve, &[16, 18, 19]);
+        assert_lengths("1", is_valid_uatp, &[15]);
+        assert_lengths("6556", is_valid_bc_card, &[16]);
+        assert_lengths("636", is_valid_interpayment, &[16, 17, 18, 19]);
+        assert_lengths("637", is_valid_instapayment, &[16]);
+    }
+
+    #[test]
+    fn test_inspect_card() {
+        let card = inspect_card("3707-897090-84107");
+        assert_eq!(card.number, "370789709084107");
+        assert_eq!(card.brand, Some("Amex"));
+        assert_eq!(card.iin.as_deref(), Some("370789"));
+        assert_eq!(card.last4.as_deref(), Some("4107"));
+        assert!(card.length_valid && card.luhn_valid);
+        assert_eq!(card.error, None);
+
+        // each failure reports the first reason
+        assert_eq!(inspect_card(" - ").error, Some(CardError::Empty));
+        assert_eq!(inspect_card("4035 3005 3980 408x").error, Some(CardError::InvalidCharacters));
+        assert_eq!(inspect_card("4035 3005 3980 408x").iin, None);
+        let unknown = inspect_card("9999999999999995");
+        assert_eq!((unknown.error, unknown.brand, unknown.length_valid), (Some(CardError::UnknownBrand), None, false));
+        assert!(unknown.luhn_valid);
+        let length = inspect_card("54631135899823");
+        assert_eq!((length.error, length.luhn_valid), (Some(CardError::Length), false));
+        let luhn = inspect_card("5463113589982387");
+        assert_eq!((luhn.error, luhn.length_valid), (Some(CardError::Luhn), true));
+        // too short for an IIN
+        let short = inspect_card("4035");
+        assert_eq!((short.iin, short.last4.as_deref()), (None, Some("4035")));
+    }
+
+    #[test]
+    fn test_inspect_card_co_brand() {
+        let visa = inspect_card(&card("4970", 16));
+        assert_eq!((visa.brand, visa.co_brand), (Some("Visa"), Some("Cartes Bancaires")));
+        assert_eq!(inspect_card(&card("4035", 16)).co_brand, None);
+        assert_eq!(inspect_card(&card("5463", 16)).co_brand, None);
+        // too short for an IIN
+        assert_eq!(inspect_card("497").co_brand, None);
+    }
+
+    #[test]
+    fn test_format_card() {
+        assert_eq!(format_card("4035300539804083", ' ').as_deref(), Some("4035 3005 3980 4083"));
+        assert_eq!(format_card("3707 8970 9084 107", ' ').as_deref(), Some("3707 897090 84107"));
+        assert_eq!(format_card("30043277253245", '-').as_deref(), Some("3004-327725-3245"));
+        assert_eq!(format_card("4035300539804083123", ' ').as_deref(), Some("4035 3005 3980 4083 123"));
+        // 15 digits of another brand, and 13 digits
+        assert_eq!(format_card("135412345677772", ' ').as_deref(), Some("1354 1234 5677 772"));
+        assert_eq!(format_card("4222222222222", ' ').as_deref(), Some("4222 2222 2222 2"));
+        // unknown brands are grouped by 4
+        assert_eq!(format_card("9999999999999995", ' ').as_deref(), Some("9999 9999 9999 9995"));
+        assert_eq!(format_card("", ' '), None);
+        assert_eq!(format_card("4035 3005 3980 408x", ' '), None);
+    }
+
+    #[test]
+    fn test_mask_card() {
+        assert_eq!(
+            mask_card("4035300539804083", CardMask::FirstSixLastFour, '*').as_deref(),
+            Some("403530******4083")
+        );
+        assert_eq!(mask_card("4035300539804083", CardMask::LastFour, 'X').as_deref(), Some("XXXXXXXXXXXX4083"));
+        // the original separators stay in place
+        assert_eq!(
+            mask_card("4035 30053980 4083", CardMask::FirstSixLastFour, '#').as_deref(),
+            Some("4035 30###### 4083")
+        );
+        assert_eq!(
+            mask_card("4035-3005-3980-4083-123", CardMask::LastFour, '*').as_deref(),
+            Some("****-****-****-***3-123")
+        );
+        // under 16 digits at least half of the number is masked
+        assert_eq!(
+            mask_card(" 3707-897090-84107 ", CardMask::FirstSixLastFour, '*').as_deref(),
+            Some("370*-******-*4107")
+        );
+        assert_eq!(mask_card("30043277253245", CardMask::FirstSixLastFour, '*').as_deref(), Some("300*******3245"));
+        assert_eq!(mask_card("4222222222222", CardMask::FirstSixLastFour, '*').as_deref(), Some("42*******2222"));
+        // shortest maskable number, too short & not a number
+        assert_eq!(mask_card("679626552024", CardMask::FirstSixLastFour, '*').as_deref(), Some("67******2024"));
+        assert_eq!(mask_card("67962655202", CardMask::LastFour, '*'), None);
+        assert_eq!(mask_card("4035 3005 3980 408x", CardMask::LastFour, '*'), None);
+    }
+}
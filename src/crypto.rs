@@ -1,4 +1,24 @@
 use regex::Regex;
+use sha2::{Digest, Sha256};
+
+mod accounts;
+mod descriptors;
+mod keys;
+mod names;
+mod nostr;
+mod registry;
+mod transactions;
+
+pub use accounts::{is_aptos, is_hedera, is_near, is_sui, normalize_move_address};
+pub use descriptors::{descriptor_checksum, is_descriptor, is_descriptor_checksum_valid};
+pub use keys::{is_keystore, is_private_key, is_private_key_hex, is_wif, which_private_key};
+pub use names::{ens_namehash, is_ens_name, is_unstoppable_domain, normalize_ens_name, which_crypto_name};
+pub use nostr::{decode_nostr, is_nostr, is_nostr_secret, which_nostr, NostrEntity};
+pub use registry::{register_coin, CoinValidator};
+pub use transactions::{
+    is_bitcoin_txid, is_ethereum_tx_hash, is_ripple_tx_hash, is_solana_signature, is_tron_tx_hash,
+    is_transaction_any, which_transaction,
+};
 
 lazy_static! {
     /// Bitcoin Regex Pattern
@@ -21,7 +41,9 @@ lazy_static! {
     static ref XRP: Regex = Regex::new(r"(?i)^r|X[0-9a-zA-Z]{33,47}$").unwrap();
 }
 
-enum Type {
+/// Built-in cryptocurrencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
     Bitcoin,
     BitcoinCash,
     Ethereum,
@@ -31,10 +53,14 @@ enum Type {
     Monero,
     Neo,
     Ripple,
+    Hedera,
+    Near,
+    Aptos,
+    Sui,
 }
 
-impl Type {
-    fn name<'a>(&self) -> &'a str {
+impl CoinValidator for Type {
+    fn name(&self) -> &'static str {
         match *self {
             Type::Bitcoin => "Bitcoin",
             Type::BitcoinCash => "Bitcoin Cash",
@@ -45,20 +71,53 @@ impl Type {
             Type::Monero => "Monero",
             Type::Neo => "Neo",
             Type::Ripple => "Ripple",
+            Type::Hedera => "Hedera",
+            Type::Near => "NEAR",
+            Type::Aptos => "Aptos",
+            Type::Sui => "Sui",
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        match *self {
+            Type::Hedera => is_hedera(value),
+            Type::Near => is_near(value),
+            Type::Aptos => is_aptos(value),
+            Type::Sui => is_sui(value),
+            _ => match self.pattern() {
+                Some(x) => x.is_match(value),
+                None => false,
+            },
+        }
+    }
+
+    fn is_detectable(&self, value: &str) -> bool {
+        match *self {
+            // without a checksum, `shard.realm.num` is any version string
+            Type::Hedera => accounts::is_hedera_detectable(value),
+            // top level NEAR names & implicit accounts can't be told apart from plain words & digests
+            Type::Near => accounts::is_near_detectable(value),
+            // Move addresses are `0x` and a bare 32 byte hash (or a short
+            // reserved address), same as any tx hash: only `is_aptos` / `is_sui`
+            Type::Aptos | Type::Sui => false,
+            _ => self.is_valid(value),
         }
     }
+}
 
-    fn pattern<'a>(&self) -> &'a Regex {
+impl Type {
+    fn pattern<'a>(&self) -> Option<&'a Regex> {
         match *self {
-            Type::Bitcoin => &BTC,
-            Type::BitcoinCash => &BCH,
-            Type::Ethereum => &ETH,
-            Type::Litecoin => &LTC,
-            Type::Dodge => &DODGE,
-            Type::Dash => &DASH,
-            Type::Monero => &XMR,
-            Type::Neo => &NEO,
-            Type::Ripple => &XRP,
+            Type::Bitcoin => Some(&BTC),
+            Type::BitcoinCash => Some(&BCH),
+            Type::Ethereum => Some(&ETH),
+            Type::Litecoin => Some(&LTC),
+            Type::Dodge => Some(&DODGE),
+            Type::Dash => Some(&DASH),
+            Type::Monero => Some(&XMR),
+            Type::Neo => Some(&NEO),
+            Type::Ripple => Some(&XRP),
+            Type::Hedera | Type::Near | Type::Aptos | Type::Sui => None,
         }
     }
 
@@ -73,14 +132,38 @@ impl Type {
             Type::Monero,
             Type::Neo,
             Type::Ripple,
+            Type::Hedera,
+            Type::Near,
+            Type::Aptos,
+            Type::Sui,
         ]
     }
 }
 
+/// Decode a Base58Check string and verify its double SHA256 checksum,
+/// returning the payload without the checksum.
+fn base58check_decode(value: &str) -> Option<Vec<u8>> {
+    let data = bs58::decode(value).into_vec().ok()?;
+    if data.len() < 5 {
+        return None
+    }
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    let hash = Sha256::digest(Sha256::digest(payload));
+    if &hash[..4] != checksum {
+        return None
+    }
+    Some(payload.to_vec())
+}
+
+/// Encode bytes as lowercase hex
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Evaluate CryptoCurrency & Validate
 fn validate(value: &str) -> bool {
     for cryptocurrency in Type::all() {
-        if cryptocurrency.pattern().is_match(&value) {
+        if cryptocurrency.is_detectable(value) {
             return true
         }
     }
@@ -214,7 +297,7 @@ pub fn is_cryptocurrency_any(value: &str) -> bool {
     //!     assert_eq!(is_cryptocurrency_any("<cryptocurrency address>"), false);
     //! }
     //! ```
-    validate(value)
+    registry::validators().iter().any(|x| x.is_detectable(value))
 }
 
 pub fn which_cryptocurrency(value: &str) -> Option<&str> {
@@ -227,8 +310,8 @@ pub fn which_cryptocurrency(value: &str) -> Option<&str> {
     //!     assert_eq!(which_cryptocurrency("<cryptocurrency address>"), None);
     //! }
     //! ```
-    for cryptocurrency in Type::all() {
-        if cryptocurrency.pattern().is_match(&value) {
+    for cryptocurrency in registry::validators() {
+        if cryptocurrency.is_detectable(value) {
             return Some(cryptocurrency.name())
         }
     }
@@ -259,16 +342,68 @@ mod tests {
         assert_eq!(which_cryptocurrency("AeHauBkGkHPTxh4PEUhNr7WRgivmcdCRnR"), Some("Neo"));
         // Ripple
         assert_eq!(which_cryptocurrency("rUocf1ixKzTuEe34kmVhRvGqNCofY1NJzV"), Some("Ripple"));
+        // Hedera
+        assert_eq!(which_cryptocurrency("0.0.123-vfmkw"), Some("Hedera"));
+        assert_eq!(which_cryptocurrency("1.2.3"), None);
+        // NEAR
+        assert_eq!(which_cryptocurrency("alice.near"), Some("NEAR"));
+        // a sha256 digest isn't a NEAR implicit account
+        assert_ne!(
+            which_cryptocurrency("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"),
+            Some("NEAR")
+        );
+        // Aptos & Sui addresses can't be told apart from tx hashes
+        assert_eq!(which_cryptocurrency("0xeeff357ea5c1a4e7bc11b2b17ff2dc2dcca69750bfef1e1ebcaccf8c8018175b"), None);
+        assert_eq!(which_cryptocurrency("0x1"), None);
         // No coin identified
         assert_eq!(which_cryptocurrency("LQ4i7FLNbtJAFZq"), None);
     }
 
+    #[test]
+    fn test_coin_validator() {
+        // valid addresses that which_cryptocurrency leaves out
+        assert!(Type::Aptos.is_valid("0x1"));
+        assert!(!Type::Aptos.is_detectable("0x1"));
+        assert!(Type::Sui.is_valid("0xdee9"));
+        assert!(!Type::Sui.is_detectable("0xdee9"));
+        assert!(Type::Hedera.is_valid("1.2.3"));
+        assert!(!Type::Hedera.is_detectable("1.2.3"));
+        assert!(Type::Near.is_valid("alice"));
+        assert!(!Type::Near.is_detectable("alice"));
+        assert!(!is_cryptocurrency_any("0x1"));
+        // other coins are detected whenever they're valid
+        assert!(Type::Bitcoin.is_detectable("1GiWxH6PzSSmbdcK72XfGpqhjSb6nae6h9"));
+        assert!(!Type::Aptos.is_valid("0x10"));
+    }
+
     #[test]
     fn test_is_cryptocurrency_any() {
         assert!(is_cryptocurrency_any("D6K2nqqQKycTucCSFSHhpiig4yQ6NPQRf9"));
         assert!(!is_cryptocurrency_any("LQ4i7FLNbtJAFZq"));
     }
 
+    #[test]
+    fn test_custom_cryptocurrency() {
+        struct Internal;
+
+        impl CoinValidator for Internal {
+            fn name(&self) -> &'static str {
+                "Internal Token"
+            }
+
+            fn is_valid(&self, value: &str) -> bool {
+                value.starts_with("int_") && value.len() == 24
+            }
+        }
+
+        assert!(!is_cryptocurrency_any("int_0123456789abcdefghij"));
+        register_coin(Internal);
+        assert!(is_cryptocurrency_any("int_0123456789abcdefghij"));
+        assert_eq!(which_cryptocurrency("int_0123456789abcdefghij"), Some("Internal Token"));
+        // built-in coins are still recognized
+        assert_eq!(which_cryptocurrency("1GiWxH6PzSSmbdcK72XfGpqhjSb6nae6h9"), Some("Bitcoin"));
+    }
+
     #[test]
     fn test_is_bitcoin() {
         assert!(is_bitcoin("1GiWxH6PzSSmbdcK72XfGpqhjSb6nae6h9"))
@@ -0,0 +1,267 @@
+use regex::Regex;
+
+lazy_static! {
+    /// Hedera `shard.realm.num` with an optional HIP-15 checksum
+    static ref HEDERA: Regex = Regex::new(r"^(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)(-([a-z]{5}))?$").unwrap();
+    /// NEAR named account: `.` separated parts of lowercase alphanumerics joined by `-` or `_`
+    static ref NEAR_NAMED: Regex = Regex::new(r"^(([a-z\d]+[\-_])*[a-z\d]+\.)*([a-z\d]+[\-_])*[a-z\d]+$").unwrap();
+    /// NEAR implicit account: hex encoded ed25519 public key
+    static ref NEAR_IMPLICIT: Regex = Regex::new(r"^[0-9a-f]{64}$").unwrap();
+    /// Aptos & Sui (Move) address, long or short form
+    static ref MOVE_ADDRESS: Regex = Regex::new(r"^0x(?i)[0-9a-f]{1,64}$").unwrap();
+}
+
+/// Ledger ids used in the HIP-15 checksum: mainnet, testnet & previewnet
+const HEDERA_LEDGERS: [u8; 3] = [0x00, 0x01, 0x02];
+
+/// NEAR account ids are between 2 and 64 characters
+const NEAR_MIN_LENGTH: usize = 2;
+const NEAR_MAX_LENGTH: usize = 64;
+
+/// Compute the HIP-15 checksum of a `shard.realm.num` address for a ledger
+fn hedera_checksum(address: &str, ledger: u8) -> String {
+    const P3: u64 = 26 * 26 * 26;
+    const P5: u64 = 26 * 26 * 26 * 26 * 26;
+    const M: u64 = 1_000_003;
+    const W: u64 = 31;
+
+    let digits: Vec<u64> = address
+        .chars()
+        .map(|c| c.to_digit(10).map(u64::from).unwrap_or(10))
+        .collect();
+    let (mut s, mut sd0, mut sd1, mut sh) = (0, 0, 0, 0);
+    for (i, d) in digits.iter().enumerate() {
+        s = (W * s + d) % P3;
+        if i % 2 == 0 {
+            sd0 = (sd0 + d) % 11;
+        } else {
+            sd1 = (sd1 + d) % 11;
+        }
+    }
+    // ledger id followed by 6 zero bytes
+    for h in [ledger, 0, 0, 0, 0, 0, 0].iter() {
+        sh = (W * sh + u64::from(*h)) % P5;
+    }
+    let c = ((((address.len() as u64 % 5) * 11 + sd0) * 11 + sd1) * P3 + s + sh) % P5;
+    let mut cp = (c * M) % P5;
+    let mut checksum = vec![0u8; 5];
+    for x in checksum.iter_mut().rev() {
+        *x = b'a' + (cp % 26) as u8;
+        cp /= 26;
+    }
+    String::from_utf8(checksum).unwrap()
+}
+
+pub fn is_hedera(value: &str) -> bool {
+    //! Check if the given value is a Hedera account id (`shard.realm.num`).
+    //! An optional HIP-15 checksum suffix (`0.0.123-vfmkw`) is verified
+    //! against the mainnet, testnet and previewnet ledgers.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_hedera;
+    //! fn main() {
+    //!     assert!(is_hedera("0.0.123-vfmkw"));
+    //!     assert!(!is_hedera("0.0.123-abcde"));
+    //! }
+    //! ```
+    let captures = match HEDERA.captures(value) {
+        Some(x) => x,
+        None => return false,
+    };
+    // each part is a signed 64 bit number on the ledger
+    for i in 1..=3 {
+        if captures[i].parse::<i64>().is_err() {
+            return false
+        }
+    }
+    match captures.get(5) {
+        Some(checksum) => {
+            let address = &value[..value.len() - 6];
+            HEDERA_LEDGERS
+                .iter()
+                .any(|ledger| hedera_checksum(address, *ledger) == checksum.as_str())
+        }
+        None => true,
+    }
+}
+
+/// Hedera account ids that can be told apart from version strings and
+/// dotted numbers: those with a HIP-15 checksum suffix
+pub(super) fn is_hedera_detectable(value: &str) -> bool {
+    HEDERA.captures(value).is_some_and(|x| x.get(5).is_some()) && is_hedera(value)
+}
+
+pub fn is_near(value: &str) -> bool {
+    //! Check if the given value is a NEAR account id:
+    //! a named account (`alice.near`) or an implicit account (64 lowercase hex).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_near;
+    //! fn main() {
+    //!     assert!(is_near("alice.near"));
+    //!     assert!(!is_near("Alice.near"));
+    //! }
+    //! ```
+    if value.len() < NEAR_MIN_LENGTH || value.len() > NEAR_MAX_LENGTH {
+        return false
+    }
+    NEAR_IMPLICIT.is_match(value) || NEAR_NAMED.is_match(value)
+}
+
+/// NEAR accounts that can be told apart from plain words and hex digests:
+/// named accounts under `.near`/`.testnet`. Implicit accounts are only
+/// recognized by `is_near`.
+pub(super) fn is_near_detectable(value: &str) -> bool {
+    is_near(value) && (value.ends_with(".near") || value.ends_with(".testnet"))
+}
+
+pub fn normalize_move_address(value: &str) -> Option<String> {
+    //! Normalize an Aptos or Sui address to its long form:
+    //! `0x` followed by 64 lowercase hex characters.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::normalize_move_address;
+    //! fn main() {
+    //!     assert_eq!(
+    //!         normalize_move_address("0x1"),
+    //!         Some("0x0000000000000000000000000000000000000000000000000000000000000001".to_string())
+    //!     );
+    //! }
+    //! ```
+    if !MOVE_ADDRESS.is_match(value) {
+        return None
+    }
+    Some(format!("0x{:0>64}", value[2..].to_lowercase()))
+}
+
+/// Aptos addresses: long form, or short form for the special
+/// addresses `0x0` to `0xf` (AIP-40)
+fn is_aptos_address(value: &str) -> bool {
+    if !MOVE_ADDRESS.is_match(value) {
+        return false
+    }
+    let hex = &value[2..];
+    if hex.len() == 64 {
+        return true
+    }
+    let significant = hex.trim_start_matches('0');
+    match u8::from_str_radix(if significant.is_empty() { "0" } else { significant }, 16) {
+        Ok(x) => x <= 0xf,
+        Err(_) => false,
+    }
+}
+
+pub fn is_aptos(value: &str) -> bool {
+    //! Check if the given value is an Aptos account address (AIP-40):
+    //! `0x` and 64 hex characters, or the short form of a reserved address (`0x1`).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_aptos;
+    //! fn main() {
+    //!     assert!(is_aptos("0x1"));
+    //!     assert!(!is_aptos("0xf1"));
+    //! }
+    //! ```
+    is_aptos_address(value)
+}
+
+pub fn is_sui(value: &str) -> bool {
+    //! Check if the given value is a Sui address: `0x` and 1 to 64 hex
+    //! characters. Any address may be written without its leading zeros,
+    //! `normalize_move_address` expands it to the long form.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_sui;
+    //! fn main() {
+    //!     assert!(is_sui("0x2"));
+    //!     assert!(is_sui("0xdee9"));
+    //!     assert!(!is_sui("dee9"));
+    //! }
+    //! ```
+    MOVE_ADDRESS.is_match(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_hedera() {
+        assert!(is_hedera("0.0.123"));
+        assert!(is_hedera("0.0.123-vfmkw"));
+        assert!(is_hedera("0.0.123-esxsf"));
+        assert!(is_hedera("0.0.1-dfkxr"));
+        assert!(is_hedera("1.2.3-islfi"));
+        assert!(!is_hedera("0.0.123-vfmkx"));
+        assert!(!is_hedera("0.0.01"));
+        assert!(!is_hedera("0.0"));
+        assert!(!is_hedera("0.0.99999999999999999999"));
+    }
+
+    #[test]
+    fn test_is_hedera_detectable() {
+        assert!(is_hedera_detectable("0.0.123-vfmkw"));
+        assert!(!is_hedera_detectable("0.0.123-vfmkx"));
+        assert!(!is_hedera_detectable("0.0.123"));
+        assert!(!is_hedera_detectable("1.2.3"));
+    }
+
+    #[test]
+    fn test_is_near() {
+        assert!(is_near("alice.near"));
+        assert!(is_near("app_1-x.alice.testnet"));
+        assert!(is_near("near"));
+        assert!(is_near("98793cd91a3f870fb126f66285808c7e094afcfc4eda8a970f6648cdf0dbd6de"));
+        assert!(!is_near("a"));
+        assert!(!is_near("alice..near"));
+        assert!(!is_near("-alice.near"));
+        assert!(!is_near("alice_.near"));
+        assert!(!is_near(&format!("{}.near", "a".repeat(60))));
+    }
+
+    #[test]
+    fn test_is_near_detectable() {
+        assert!(is_near_detectable("alice.near"));
+        assert!(is_near_detectable("app.alice.testnet"));
+        assert!(!is_near_detectable("alice"));
+        // implicit accounts look like any sha256 digest
+        assert!(!is_near_detectable("98793cd91a3f870fb126f66285808c7e094afcfc4eda8a970f6648cdf0dbd6de"));
+    }
+
+    #[test]
+    fn test_normalize_move_address() {
+        assert_eq!(normalize_move_address("0xA"), Some(format!("0x{:0>64}", "a")));
+        assert_eq!(normalize_move_address("0x"), None);
+        assert_eq!(normalize_move_address(&format!("0x{}", "1".repeat(65))), None);
+    }
+
+    #[test]
+    fn test_is_aptos() {
+        assert!(is_aptos("0x1"));
+        assert!(is_aptos("0x0000000000000000000000000000000000000000000000000000000000000001"));
+        assert!(is_aptos("0xeeff357ea5c1a4e7bc11b2b17ff2dc2dcca69750bfef1e1ebcaccf8c8018175b"));
+        assert!(!is_aptos("0x0eeff357ea5c1a4e7bc11b2b17ff2dc2dcca69750bfef1e1ebcaccf8c8018175b"));
+        assert!(is_aptos("0xa"));
+        assert!(is_aptos("0xb"));
+        assert!(is_aptos("0x00f"));
+        assert!(!is_aptos("0x10"));
+    }
+
+    #[test]
+    fn test_is_sui() {
+        assert!(is_sui("0x2"));
+        assert!(is_sui("0xdee9"));
+        assert!(is_sui("0xDEE9"));
+        assert!(is_sui("0x000000000000000000000000000000000000000000000000000000000000dee9"));
+        assert_eq!(normalize_move_address("0xdee9"), Some(format!("0x{:0>64}", "dee9")));
+        assert!(!is_sui("0x"));
+        assert!(!is_sui("dee9"));
+        assert!(!is_sui("0xdeg9"));
+        assert!(!is_sui(&format!("0x{}", "1".repeat(65))));
+    }
+}
@@ -0,0 +1,379 @@
+use regex::Regex;
+
+use super::{base58check_decode, is_wif};
+
+lazy_static! {
+    /// Key origin fingerprint
+    static ref FINGERPRINT: Regex = Regex::new(r"^(?i)[0-9a-f]{8}$").unwrap();
+    /// Compressed (02/03) or uncompressed (04) public key
+    static ref PUBKEY: Regex = Regex::new(r"^(?i)(0[23][0-9a-f]{64}|04[0-9a-f]{128})$").unwrap();
+    /// x-only public key (taproot)
+    static ref XONLY_PUBKEY: Regex = Regex::new(r"^(?i)[0-9a-f]{64}$").unwrap();
+    /// Raw script hex
+    static ref RAW_HEX: Regex = Regex::new(r"^(?i)([0-9a-f]{2})*$").unwrap();
+    /// Single derivation step, optionally hardened
+    static ref PATH_STEP: Regex = Regex::new(r"^([0-9]+)['hH]?$").unwrap();
+    /// Multipath derivation step (BIP-389), eg: `<0;1>`
+    static ref MULTIPATH_STEP: Regex = Regex::new(r"^<([0-9]+['hH]?;)+[0-9]+['hH]?>$").unwrap();
+}
+
+/// Character set covered by the descriptor checksum
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+/// Character set of the checksum itself
+const CHECKSUM_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// Deepest taproot script tree allowed (BIP-386)
+const MAX_TREE_DEPTH: usize = 128;
+/// BCH generator for the descriptor checksum
+const GENERATOR: [u64; 5] = [0xf5dee51989, 0xa9fdca3312, 0x1bab10e32d, 0x3706b1677a, 0x644d626ffd];
+
+/// Context a script expression appears in
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Top,
+    Sh,
+    Wsh,
+    Tr,
+}
+
+fn polymod(symbols: &[u64]) -> u64 {
+    let mut chk: u64 = 1;
+    for value in symbols {
+        let top = chk >> 35;
+        chk = ((chk & 0x7_ffff_ffff) << 5) ^ value;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// Map descriptor characters to checksum symbols
+fn expand(value: &str) -> Option<Vec<u64>> {
+    let mut symbols = vec![];
+    let mut groups = vec![];
+    for c in value.chars() {
+        let v = INPUT_CHARSET.find(c)? as u64;
+        symbols.push(v & 31);
+        groups.push(v >> 5);
+        if groups.len() == 3 {
+            symbols.push(groups[0] * 9 + groups[1] * 3 + groups[2]);
+            groups.clear();
+        }
+    }
+    match groups.len() {
+        1 => symbols.push(groups[0]),
+        2 => symbols.push(groups[0] * 3 + groups[1]),
+        _ => {}
+    }
+    Some(symbols)
+}
+
+/// Split arguments on top level commas
+fn split_args(value: &str) -> Vec<&str> {
+    let mut args = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(&value[start..]);
+    args
+}
+
+/// Check that (), [] and {} are properly nested
+fn is_balanced(value: &str) -> bool {
+    let mut stack = vec![];
+    for c in value.chars() {
+        match c {
+            '(' | '[' | '{' => stack.push(c),
+            ')' if stack.pop() != Some('(') => return false,
+            ']' if stack.pop() != Some('[') => return false,
+            '}' if stack.pop() != Some('{') => return false,
+            _ => {}
+        }
+    }
+    stack.is_empty()
+}
+
+/// Check a derivation path step, rejecting indexes of 2^31 and above
+fn is_path_step_valid(step: &str) -> bool {
+    match PATH_STEP.captures(step) {
+        Some(x) => match x[1].parse::<u32>() {
+            Ok(index) => index < 0x8000_0000,
+            Err(_) => false,
+        },
+        None => false,
+    }
+}
+
+/// Check the `[fingerprint/path]` key origin
+fn is_origin_valid(origin: &str) -> bool {
+    let mut parts = origin.split('/');
+    match parts.next() {
+        Some(x) if FINGERPRINT.is_match(x) => {}
+        _ => return false,
+    }
+    parts.all(is_path_step_valid)
+}
+
+/// Check an extended key (xpub/xprv/tpub/...) and its derivation suffix
+fn is_extended_key_valid(key: &str) -> bool {
+    let mut parts = key.split('/');
+    match parts.next().and_then(base58check_decode) {
+        Some(x) if x.len() == 78 => {}
+        _ => return false,
+    }
+    let steps: Vec<&str> = parts.collect();
+    for (i, step) in steps.iter().enumerate() {
+        let last = i == steps.len() - 1;
+        let valid = is_path_step_valid(step)
+            || MULTIPATH_STEP.is_match(step)
+            || (last && ["*", "*'", "*h", "*H"].contains(step));
+        if !valid {
+            return false
+        }
+    }
+    true
+}
+
+/// Check a key expression with its optional origin
+fn is_key_valid(value: &str, context: Context) -> bool {
+    let key = if let Some(rest) = value.strip_prefix('[') {
+        let end = match rest.find(']') {
+            Some(x) => x,
+            None => return false,
+        };
+        if !is_origin_valid(&rest[..end]) {
+            return false
+        }
+        &rest[end + 1..]
+    } else {
+        value
+    };
+    if key.chars().all(|c| c.is_ascii_hexdigit()) {
+        return match context {
+            Context::Tr => XONLY_PUBKEY.is_match(key) || (PUBKEY.is_match(key) && !key.starts_with("04")),
+            // segwit only allows compressed keys
+            Context::Wsh => PUBKEY.is_match(key) && !key.starts_with("04"),
+            _ => PUBKEY.is_match(key),
+        }
+    }
+    is_wif(key) || is_extended_key_valid(key)
+}
+
+/// Check `multi(k, KEY, ...)` arguments
+fn is_multi_valid(args: &[&str], max_keys: usize, context: Context) -> bool {
+    if args.len() < 2 {
+        return false
+    }
+    let threshold: usize = match args[0].parse() {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    let keys = &args[1..];
+    threshold >= 1
+        && threshold <= keys.len()
+        && keys.len() <= max_keys
+        && keys.iter().all(|key| is_key_valid(key, context))
+}
+
+/// Check a taproot script tree: a leaf script or `{TREE,TREE}`,
+/// `depth` being the number of enclosing branches
+fn is_tree_valid(value: &str, depth: usize) -> bool {
+    if let Some(inner) = value.strip_prefix('{').and_then(|x| x.strip_suffix('}')) {
+        if depth >= MAX_TREE_DEPTH {
+            return false
+        }
+        let branches = split_args(inner);
+        return branches.len() == 2 && branches.iter().all(|x| is_tree_valid(x, depth + 1))
+    }
+    is_script_valid(value, Context::Tr)
+}
+
+/// Check a script expression in its context
+fn is_script_valid(value: &str, context: Context) -> bool {
+    let open = match value.find('(') {
+        Some(x) => x,
+        None => return false,
+    };
+    if !value.ends_with(')') {
+        return false
+    }
+    let name = &value[..open];
+    let args = split_args(&value[open + 1..value.len() - 1]);
+    match (name, context) {
+        ("sh", Context::Top) => args.len() == 1 && is_script_valid(args[0], Context::Sh),
+        ("wsh", Context::Top) | ("wsh", Context::Sh) => args.len() == 1 && is_script_valid(args[0], Context::Wsh),
+        ("wpkh", Context::Top) | ("wpkh", Context::Sh) => args.len() == 1 && is_key_valid(args[0], Context::Wsh),
+        ("combo", Context::Top) => args.len() == 1 && is_key_valid(args[0], context),
+        ("pk", _) | ("pkh", _) => args.len() == 1 && is_key_valid(args[0], context),
+        ("multi", Context::Top) | ("sortedmulti", Context::Top) => is_multi_valid(&args, 3, context),
+        ("multi", Context::Sh) | ("sortedmulti", Context::Sh) => is_multi_valid(&args, 15, context),
+        ("multi", Context::Wsh) | ("sortedmulti", Context::Wsh) => is_multi_valid(&args, 20, context),
+        ("multi_a", Context::Tr) | ("sortedmulti_a", Context::Tr) => is_multi_valid(&args, 999, context),
+        ("tr", Context::Top) => match args.len() {
+            1 => is_key_valid(args[0], Context::Tr),
+            2 => is_key_valid(args[0], Context::Tr) && is_tree_valid(args[1], 0),
+            _ => false,
+        },
+        ("addr", Context::Top) => args.len() == 1 && !args[0].is_empty(),
+        ("raw", Context::Top) => args.len() == 1 && RAW_HEX.is_match(args[0]),
+        ("rawtr", Context::Top) => args.len() == 1 && is_key_valid(args[0], Context::Tr),
+        _ => false,
+    }
+}
+
+pub fn descriptor_checksum(value: &str) -> Option<String> {
+    //! Compute the 8 character BIP-380 checksum of an output descriptor.
+    //! Any existing `#checksum` suffix is ignored.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::descriptor_checksum;
+    //! fn main() {
+    //!     assert_eq!(descriptor_checksum("raw(deadbeef)"), Some("89f8spxm".to_string()));
+    //! }
+    //! ```
+    let descriptor = value.split('#').next().unwrap_or(value);
+    let mut symbols = expand(descriptor)?;
+    symbols.extend_from_slice(&[0; 8]);
+    let checksum = polymod(&symbols) ^ 1;
+    let charset = CHECKSUM_CHARSET.as_bytes();
+    Some(
+        (0..8)
+            .map(|i| charset[((checksum >> (5 * (7 - i))) & 31) as usize] as char)
+            .collect(),
+    )
+}
+
+pub fn is_descriptor_checksum_valid(value: &str) -> bool {
+    //! Check that an output descriptor carries a valid `#checksum` suffix.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_descriptor_checksum_valid;
+    //! fn main() {
+    //!     assert!(is_descriptor_checksum_valid("raw(deadbeef)#89f8spxm"));
+    //!     assert!(!is_descriptor_checksum_valid("raw(deadbeef)"));
+    //! }
+    //! ```
+    let parts: Vec<&str> = value.rsplitn(2, '#').collect();
+    if parts.len() != 2 || parts[0].len() != 8 {
+        return false
+    }
+    let mut symbols = match expand(parts[1]) {
+        Some(x) => x,
+        None => return false,
+    };
+    for c in parts[0].chars() {
+        match CHECKSUM_CHARSET.find(c) {
+            Some(x) => symbols.push(x as u64),
+            None => return false,
+        }
+    }
+    polymod(&symbols) == 1
+}
+
+pub fn is_descriptor(value: &str) -> bool {
+    //! Check if the given value is a valid Bitcoin output descriptor (BIP-380).
+    //! Script expressions, key origins and derivation paths are validated,
+    //! and the `#checksum` suffix is verified when present.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_descriptor;
+    //! fn main() {
+    //!     assert!(is_descriptor("wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)"));
+    //!     assert!(!is_descriptor("wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"));
+    //! }
+    //! ```
+    if value.contains('#') && !is_descriptor_checksum_valid(value) {
+        return false
+    }
+    let descriptor = value.split('#').next().unwrap_or(value);
+    is_balanced(descriptor) && is_script_valid(descriptor, Context::Top)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XPUB: &str = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+    const PUBKEY_A: &str = "03a34b99f22c790c4e36b2b3c2c35a36db06226e41c692fc82b8b56ac1c540c5bd";
+    const PUBKEY_B: &str = "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+
+    #[test]
+    fn test_descriptor_checksum() {
+        assert_eq!(descriptor_checksum("raw(deadbeef)"), Some("89f8spxm".to_string()));
+        assert_eq!(descriptor_checksum("raw(deadbeef)#ignored"), Some("89f8spxm".to_string()));
+        // character outside of the input charset
+        assert_eq!(descriptor_checksum("raw(deadbeef)\u{e9}"), None);
+    }
+
+    #[test]
+    fn test_is_descriptor_checksum_valid() {
+        assert!(is_descriptor_checksum_valid("raw(deadbeef)#89f8spxm"));
+        assert!(!is_descriptor_checksum_valid("raw(deadbeef)#"));
+        assert!(!is_descriptor_checksum_valid("raw(deadbeef)#89f8spxmx"));
+        assert!(!is_descriptor_checksum_valid("raw(deadbeef)#89f8spxn"));
+        assert!(!is_descriptor_checksum_valid("raw(dedbeef)#89f8spxm"));
+    }
+
+    #[test]
+    fn test_is_descriptor() {
+        // Valid
+        assert!(is_descriptor("raw(deadbeef)#89f8spxm"));
+        assert!(is_descriptor(&format!("pkh({}/1/*)", XPUB)));
+        assert!(is_descriptor(&format!("wpkh([d34db33f/84'/0'/0']{}/0/*)", XPUB)));
+        assert!(is_descriptor(&format!("wpkh([d34db33f/84h/0h/0h]{}/<0;1>/*)", XPUB)));
+        assert!(is_descriptor(&format!("sh(wsh(sortedmulti(2,{},{},{}/0/*)))", PUBKEY_A, PUBKEY_B, XPUB)));
+        assert!(is_descriptor(&format!("tr({},{{pk({}),multi_a(1,{},{})}})", PUBKEY_A, PUBKEY_B, PUBKEY_A, PUBKEY_B)));
+        assert!(is_descriptor("pk([deadbeef/1/2'/3/4']L4rK1yDtCWekvXuE6oXD9jCYfFNV2cWRpVuPLBcCU2z8TrisoyY1)"));
+        let descriptor = format!("wsh(multi(1,{},{}))", PUBKEY_A, PUBKEY_B);
+        let checksum = descriptor_checksum(&descriptor).unwrap();
+        assert!(is_descriptor(&format!("{}#{}", descriptor, checksum)));
+
+        // Invalid
+        // unbalanced
+        assert!(!is_descriptor(&format!("wpkh({}", PUBKEY_B)));
+        assert!(!is_descriptor(&format!("wpkh([d34db33f/84'{})", XPUB)));
+        // bad fingerprint
+        assert!(!is_descriptor(&format!("wpkh([d34db3/84'/0'/0']{}/0/*)", XPUB)));
+        // wildcard in the middle of a path
+        assert!(!is_descriptor(&format!("wpkh({}/*/0)", XPUB)));
+        // hardened index overflow
+        assert!(!is_descriptor(&format!("wpkh({}/2147483648)", XPUB)));
+        // threshold greater than the number of keys
+        assert!(!is_descriptor(&format!("wsh(multi(3,{},{}))", PUBKEY_A, PUBKEY_B)));
+        // wpkh inside wsh
+        assert!(!is_descriptor(&format!("wsh(wpkh({}))", PUBKEY_B)));
+        // unknown script
+        assert!(!is_descriptor(&format!("foo({})", PUBKEY_B)));
+        // wrong checksum
+        assert!(!is_descriptor(&format!("{}#89f8spxm", descriptor)));
+    }
+
+    #[test]
+    fn test_is_descriptor_tree_depth() {
+        let leaf = format!("pk({})", PUBKEY_B);
+        let tree = |depth: usize| {
+            format!("tr({},{}{}{})", PUBKEY_A, "{".repeat(depth), leaf, format!(",{}}}", leaf).repeat(depth))
+        };
+        assert!(is_descriptor(&tree(1)));
+        assert!(is_descriptor(&tree(128)));
+        assert!(!is_descriptor(&tree(129)));
+        // rejected without exhausting the stack
+        assert!(!is_descriptor(&tree(20000)));
+    }
+}
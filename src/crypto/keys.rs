@@ -0,0 +1,299 @@
+use regex::Regex;
+use serde_json::Value;
+
+use super::{base58check_decode, nostr::decode_nostr};
+
+lazy_static! {
+    /// Raw secp256k1 scalar, optionally `0x` prefixed
+    static ref HEX_KEY: Regex = Regex::new(r"^(?i)(0x)?[0-9a-f]{64}$").unwrap();
+    /// Even length hex string (keystore ciphertext, iv, salt, mac)
+    static ref HEX: Regex = Regex::new(r"^(?i)([0-9a-f]{2})+$").unwrap();
+}
+
+/// secp256k1 curve order `n`; a private key must be in `[1, n - 1]`
+const SECP256K1_ORDER: [u8; 32] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B,
+    0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+];
+
+enum Type {
+    BitcoinWif,
+    BitcoinTestnetWif,
+    LitecoinWif,
+    DogecoinWif,
+    DashWif,
+    Hex,
+    Nostr,
+    Keystore,
+}
+
+impl Type {
+    fn name<'a>(&self, compressed: bool) -> &'a str {
+        match (self, compressed) {
+            (Type::BitcoinWif, false) => "Bitcoin WIF",
+            (Type::BitcoinWif, true) => "Bitcoin WIF (compressed)",
+            (Type::BitcoinTestnetWif, false) => "Bitcoin Testnet WIF",
+            (Type::BitcoinTestnetWif, true) => "Bitcoin Testnet WIF (compressed)",
+            (Type::LitecoinWif, false) => "Litecoin WIF",
+            (Type::LitecoinWif, true) => "Litecoin WIF (compressed)",
+            (Type::DogecoinWif, false) => "Dogecoin WIF",
+            (Type::DogecoinWif, true) => "Dogecoin WIF (compressed)",
+            (Type::DashWif, false) => "Dash WIF",
+            (Type::DashWif, true) => "Dash WIF (compressed)",
+            (Type::Hex, _) => "secp256k1 Hex",
+            (Type::Nostr, _) => "Nostr Secret Key",
+            (Type::Keystore, _) => "Ethereum Keystore",
+        }
+    }
+
+    /// WIF network (version) byte
+    fn from_version(version: u8) -> Option<Type> {
+        match version {
+            0x80 => Some(Type::BitcoinWif),
+            0xEF => Some(Type::BitcoinTestnetWif),
+            0xB0 => Some(Type::LitecoinWif),
+            0x9E => Some(Type::DogecoinWif),
+            0xCC => Some(Type::DashWif),
+            _ => None,
+        }
+    }
+}
+
+/// Check that a 32 byte big-endian scalar is a usable secp256k1 private key
+fn is_scalar_valid(scalar: &[u8]) -> bool {
+    scalar.len() == 32
+        && scalar.iter().any(|b| *b != 0)
+        && scalar < &SECP256K1_ORDER[..]
+}
+
+/// Decode a WIF key into its type and compression flag
+fn decode_wif(value: &str) -> Option<(Type, bool)> {
+    let payload = base58check_decode(value)?;
+    let compressed = match payload.len() {
+        33 => false,
+        34 if payload[33] == 0x01 => true,
+        _ => return None,
+    };
+    let key_type = Type::from_version(payload[0])?;
+    if !is_scalar_valid(&payload[1..33]) {
+        return None
+    }
+    Some((key_type, compressed))
+}
+
+/// Decode a hex string of any length into bytes
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !HEX.is_match(value) {
+        return None
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Check that a JSON field is a non empty hex string
+fn is_hex_field(value: &Value, field: &str) -> bool {
+    match value.get(field).and_then(Value::as_str) {
+        Some(x) => HEX.is_match(x),
+        None => false,
+    }
+}
+
+/// Check that a JSON object carries all the given fields
+fn has_fields(value: &Value, fields: &[&str]) -> bool {
+    fields.iter().all(|field| value.get(field).is_some())
+}
+
+pub fn is_wif(value: &str) -> bool {
+    //! Check if the given value is a Wallet Import Format private key
+    //! (Bitcoin, Bitcoin Testnet, Litecoin, Dogecoin or Dash), compressed
+    //! or uncompressed, with a valid checksum and key range.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_wif;
+    //! fn main() {
+    //!     assert!(is_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"));
+    //! }
+    //! ```
+    decode_wif(value.trim()).is_some()
+}
+
+pub fn is_private_key_hex(value: &str) -> bool {
+    //! Check if the given value is a raw 64 hex secp256k1 private key
+    //! within the curve order. An optional `0x` prefix is accepted.
+    //!
+    //! Note that any 256 bit digest (eg: SHA256) has the same shape,
+    //! so this is best used to flag values that are expected to be secrets.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_private_key_hex;
+    //! fn main() {
+    //!     assert!(is_private_key_hex("0C28FCA386C7A227600B2FE50B7CAE11EC86D3BF1FBE471BE89827E19D72AA1D"));
+    //! }
+    //! ```
+    let value = value.trim();
+    if !HEX_KEY.is_match(value) {
+        return false
+    }
+    let value = value.trim_start_matches("0x").trim_start_matches("0X");
+    match decode_hex(value) {
+        Some(x) => is_scalar_valid(&x),
+        None => false,
+    }
+}
+
+pub fn is_keystore(value: &str) -> bool {
+    //! Check if the given value is a Web3 Secret Storage (v3) keystore,
+    //! ie: the encrypted JSON key file used by Ethereum wallets.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_keystore;
+    //! fn main() {
+    //!     assert!(!is_keystore(r#"{"version": 3}"#));
+    //! }
+    //! ```
+    let json: Value = match serde_json::from_str(value) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    if json.get("version").and_then(Value::as_u64) != Some(3) {
+        return false
+    }
+    // older geth versions write the `Crypto` key capitalised
+    let crypto = match json.get("crypto").or_else(|| json.get("Crypto")) {
+        Some(x) => x,
+        None => return false,
+    };
+    if crypto.get("cipher").and_then(Value::as_str).is_none()
+        || !is_hex_field(crypto, "ciphertext")
+        || !is_hex_field(crypto, "mac")
+    {
+        return false
+    }
+    match crypto.get("cipherparams") {
+        Some(x) if is_hex_field(x, "iv") => {}
+        _ => return false,
+    }
+    let kdfparams = match crypto.get("kdfparams") {
+        Some(x) => x,
+        None => return false,
+    };
+    match crypto.get("kdf").and_then(Value::as_str) {
+        Some("scrypt") => has_fields(kdfparams, &["dklen", "n", "r", "p"]) && is_hex_field(kdfparams, "salt"),
+        Some("pbkdf2") => has_fields(kdfparams, &["c", "dklen", "prf"]) && is_hex_field(kdfparams, "salt"),
+        _ => false,
+    }
+}
+
+pub fn is_private_key(value: &str) -> bool {
+    //! Check if the given value looks like a leaked private key:
+    //! a WIF key, a raw hex secp256k1 scalar, a Nostr `nsec` or an Ethereum keystore.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_private_key;
+    //! fn main() {
+    //!     assert!(is_private_key("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"));
+    //!     assert!(!is_private_key("1GiWxH6PzSSmbdcK72XfGpqhjSb6nae6h9"));
+    //! }
+    //! ```
+    which_private_key(value).is_some()
+}
+
+pub fn which_private_key(value: &str) -> Option<&str> {
+    //! Output the private key format given a private key.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::which_private_key;
+    //! fn main() {
+    //!     assert_eq!(
+    //!         which_private_key("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"),
+    //!         Some("Bitcoin WIF (compressed)")
+    //!     );
+    //! }
+    //! ```
+    if let Some((key_type, compressed)) = decode_wif(value.trim()) {
+        return Some(key_type.name(compressed))
+    }
+    if is_private_key_hex(value) {
+        return Some(Type::Hex.name(false))
+    }
+    if decode_nostr(value.trim()).map(|x| x.is_secret()).unwrap_or(false) {
+        return Some(Type::Nostr.name(false))
+    }
+    if is_keystore(value) {
+        return Some(Type::Keystore.name(false))
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": {"iv": "83dbcc02d8ccb40e466191a123791e0e"},
+            "ciphertext": "d172bf743a674da9cdad04534d56926ef8358534d458fffccd4e6ad2fbde479c",
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 262144,
+                "p": 8,
+                "r": 1,
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac": "2103ac29920d71da29f15d75b4a16dbe95cfd7ff8faea1056c33131d846e3097"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn test_which_private_key() {
+        assert_eq!(which_private_key("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"), Some("Bitcoin WIF"));
+        assert_eq!(which_private_key("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"), Some("Bitcoin WIF (compressed)"));
+        assert_eq!(which_private_key("cMzLdeGd5vEqxB8B6VFQoRopQ3sLAAvEzDAoQgvX54xwofSWj1fx"), Some("Bitcoin Testnet WIF (compressed)"));
+        assert_eq!(which_private_key("T3TccUZx4EXBZaHnFiP9eTr8igDEZoqSjNvbA56Z8vV74oyAcjTK"), Some("Litecoin WIF (compressed)"));
+        assert_eq!(which_private_key("6JDyVDw6R82kH9PsbHq3nqk8XnDoLmrFEEknLEZbHA3ZQ8cSuqc"), Some("Dogecoin WIF"));
+        assert_eq!(which_private_key("XBhGczf8xYB2r4fHjqS9wLVmgqqVwXRoHkNEpnoCtKb2x5RsXrCP"), Some("Dash WIF (compressed)"));
+        assert_eq!(which_private_key("0x0C28FCA386C7A227600B2FE50B7CAE11EC86D3BF1FBE471BE89827E19D72AA1D"), Some("secp256k1 Hex"));
+        assert_eq!(which_private_key("nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5"), Some("Nostr Secret Key"));
+        assert_eq!(which_private_key(KEYSTORE), Some("Ethereum Keystore"));
+        // public address
+        assert_eq!(which_private_key("1GiWxH6PzSSmbdcK72XfGpqhjSb6nae6h9"), None);
+    }
+
+    #[test]
+    fn test_is_wif() {
+        assert!(is_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"));
+        // bad checksum
+        assert!(!is_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK"));
+        // key equal to the curve order
+        assert!(!is_wif("L5oLkpV3aqBjhki6LmvChTCV6odsp4SXM6FfU2Gppt5kFqRzExJJ"));
+    }
+
+    #[test]
+    fn test_is_private_key_hex() {
+        assert!(is_private_key_hex("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d"));
+        assert!(!is_private_key_hex("0000000000000000000000000000000000000000000000000000000000000000"));
+        assert!(!is_private_key_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"));
+        assert!(!is_private_key_hex("0c28fca386c7a227600b2fe50b7cae11"));
+    }
+
+    #[test]
+    fn test_is_keystore() {
+        assert!(is_keystore(KEYSTORE));
+        assert!(!is_keystore(&KEYSTORE.replace("\"version\": 3", "\"version\": 1")));
+        assert!(!is_keystore(&KEYSTORE.replace("\"kdf\": \"scrypt\"", "\"kdf\": \"none\"")));
+        assert!(!is_keystore("not json"));
+    }
+}
@@ -0,0 +1,203 @@
+use ens_normalize_rs::EnsNameNormalizer;
+use regex::Regex;
+use sha3::{Digest, Keccak256};
+
+use super::to_hex;
+
+lazy_static! {
+    /// ENSIP-15 normalizer, loaded once as the spec tables are large
+    static ref ENS: EnsNameNormalizer = EnsNameNormalizer::default();
+    /// Unstoppable Domains label: lowercase letters, digits and inner hyphens
+    static ref UNSTOPPABLE_LABEL: Regex = Regex::new(r"^[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$").unwrap();
+    /// TLDs minted on the Unstoppable Domains registry
+    static ref UNSTOPPABLE_TLDS: Vec<&'static str> = vec![
+        "crypto", "nft", "x", "wallet", "bitcoin", "dao", "888", "zil", "blockchain",
+        "polygon", "unstoppable", "klever", "hi", "kresus", "anime", "manga", "binanceus",
+        "go", "altimist", "pudgy", "austin", "bitget", "pog", "clay", "witg", "metropolis",
+        "wrkx", "fun", "stepn", "secret", "raiin", "ubu", "tball", "farms", "dfz", "mumu",
+        "ethermail", "lfg", "u",
+    ];
+}
+
+enum Type {
+    Ens,
+    Unstoppable,
+}
+
+impl Type {
+    fn name<'a>(&self) -> &'a str {
+        match *self {
+            Type::Ens => "ENS",
+            Type::Unstoppable => "Unstoppable Domains",
+        }
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match *self {
+            Type::Ens => is_ens_name(value),
+            Type::Unstoppable => is_unstoppable_domain(value),
+        }
+    }
+
+    fn all() -> Vec<Type> {
+        vec![Type::Ens, Type::Unstoppable]
+    }
+}
+
+pub fn normalize_ens_name(value: &str) -> Option<String> {
+    //! Normalize an ENS name following ENSIP-15 (case folding, emoji handling,
+    //! confusable and invisible character rejection).
+    //! Returns `None` if the name cannot be normalized.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::normalize_ens_name;
+    //! fn main() {
+    //!     assert_eq!(normalize_ens_name("Vitalik.ETH"), Some("vitalik.eth".to_string()));
+    //! }
+    //! ```
+    ENS.normalize(value).ok()
+}
+
+pub fn is_ens_name(value: &str) -> bool {
+    //! Check if the given value is a valid `.eth` ENS name,
+    //! ie: it normalizes under ENSIP-15 and ends with the `eth` TLD.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_ens_name;
+    //! fn main() {
+    //!     assert!(is_ens_name("vitalik.eth"));
+    //!     assert!(!is_ens_name("vitalik.com"));
+    //! }
+    //! ```
+    match normalize_ens_name(value) {
+        Some(x) => x.ends_with(".eth"),
+        None => false,
+    }
+}
+
+pub fn ens_namehash(value: &str) -> Option<String> {
+    //! Compute the ENS namehash (EIP-137) of a name after normalizing it.
+    //! The hash is returned as `0x` prefixed lowercase hex.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::ens_namehash;
+    //! fn main() {
+    //!     assert_eq!(
+    //!         ens_namehash("eth"),
+    //!         Some("0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae".to_string())
+    //!     );
+    //! }
+    //! ```
+    let mut node = [0u8; 32];
+    if value.is_empty() {
+        return Some(format!("0x{}", to_hex(&node)))
+    }
+    let name = normalize_ens_name(value)?;
+    for label in name.rsplit('.') {
+        let mut hasher = Keccak256::new();
+        hasher.update(node);
+        hasher.update(Keccak256::digest(label.as_bytes()));
+        node.copy_from_slice(&hasher.finalize());
+    }
+    Some(format!("0x{}", to_hex(&node)))
+}
+
+pub fn is_unstoppable_domain(value: &str) -> bool {
+    //! Check if the given value is a syntactically valid Unstoppable Domains name
+    //! (eg: `brad.crypto`, `sub.name.nft`).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_unstoppable_domain;
+    //! fn main() {
+    //!     assert!(is_unstoppable_domain("brad.crypto"));
+    //!     assert!(!is_unstoppable_domain("brad.eth"));
+    //! }
+    //! ```
+    if value.len() > 253 {
+        return false
+    }
+    let labels: Vec<&str> = value.split('.').collect();
+    if labels.len() < 2 || !UNSTOPPABLE_TLDS.contains(&labels[labels.len() - 1]) {
+        return false
+    }
+    labels[..labels.len() - 1]
+        .iter()
+        .all(|label| UNSTOPPABLE_LABEL.is_match(label))
+}
+
+pub fn which_crypto_name(value: &str) -> Option<&str> {
+    //! Output the naming service given a human readable crypto name.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::which_crypto_name;
+    //! fn main() {
+    //!     assert_eq!(which_crypto_name("brad.crypto"), Some("Unstoppable Domains"));
+    //! }
+    //! ```
+    for service in Type::all() {
+        if service.is_match(value) {
+            return Some(service.name())
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_ens_name() {
+        assert_eq!(normalize_ens_name("Nick.ETH"), Some("nick.eth".to_string()));
+        assert_eq!(normalize_ens_name("\u{1F170}\u{FE0F}\u{1F171}.eth"), Some("\u{1F170}\u{1F171}.eth".to_string()));
+        // empty label
+        assert_eq!(normalize_ens_name("nick..eth"), None);
+        // zero width joiner
+        assert_eq!(normalize_ens_name("ni\u{200D}ck.eth"), None);
+        // mixed latin & cyrillic confusable
+        assert_eq!(normalize_ens_name("p\u{0430}ypal.eth"), None);
+    }
+
+    #[test]
+    fn test_is_ens_name() {
+        assert!(is_ens_name("nick.eth"));
+        assert!(is_ens_name("sub.nick.eth"));
+        assert!(!is_ens_name("nick"));
+        assert!(!is_ens_name("nick..eth"));
+    }
+
+    #[test]
+    fn test_ens_namehash() {
+        assert_eq!(ens_namehash(""), Some(format!("0x{}", "0".repeat(64))));
+        assert_eq!(
+            ens_namehash("foo.eth"),
+            Some("0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f".to_string())
+        );
+        // namehash is computed on the normalized name
+        assert_eq!(ens_namehash("FOO.eth"), ens_namehash("foo.eth"));
+        assert_eq!(ens_namehash("foo..eth"), None);
+    }
+
+    #[test]
+    fn test_is_unstoppable_domain() {
+        assert!(is_unstoppable_domain("brad.crypto"));
+        assert!(is_unstoppable_domain("my-wallet.x"));
+        assert!(is_unstoppable_domain("sub.brad.nft"));
+        assert!(!is_unstoppable_domain("-brad.crypto"));
+        assert!(!is_unstoppable_domain("Brad.crypto"));
+        assert!(!is_unstoppable_domain("crypto"));
+        assert!(!is_unstoppable_domain("brad.com"));
+    }
+
+    #[test]
+    fn test_which_crypto_name() {
+        assert_eq!(which_crypto_name("nick.eth"), Some("ENS"));
+        assert_eq!(which_crypto_name("brad.wallet"), Some("Unstoppable Domains"));
+        assert_eq!(which_crypto_name("example.com"), None);
+    }
+}
@@ -0,0 +1,272 @@
+use bech32::{primitives::decode::CheckedHrpstring, Bech32};
+
+use super::to_hex;
+
+/// TLV types used by the extended NIP-19 entities
+const TLV_SPECIAL: u8 = 0;
+const TLV_RELAY: u8 = 1;
+const TLV_AUTHOR: u8 = 2;
+const TLV_KIND: u8 = 3;
+
+/// A decoded NIP-19 entity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NostrEntity {
+    /// Bech32 prefix: `npub`, `nsec`, `note`, `nprofile`, `nevent` or `naddr`
+    pub prefix: String,
+    /// Hex public key, secret key or event id; the `d` tag identifier for `naddr`
+    pub value: String,
+    /// Relay hints (`nprofile`, `nevent`, `naddr`)
+    pub relays: Vec<String>,
+    /// Hex public key of the author (`nevent`, `naddr`)
+    pub author: Option<String>,
+    /// Event kind (`nevent`, `naddr`)
+    pub kind: Option<u32>,
+}
+
+impl NostrEntity {
+    /// `nsec` entities carry a private key and must never be shared
+    pub fn is_secret(&self) -> bool {
+        self.prefix == "nsec"
+    }
+}
+
+enum Type {
+    PublicKey,
+    SecretKey,
+    Note,
+    Profile,
+    Event,
+    Address,
+}
+
+impl Type {
+    fn name<'a>(&self) -> &'a str {
+        match *self {
+            Type::PublicKey => "Nostr Public Key",
+            Type::SecretKey => "Nostr Secret Key",
+            Type::Note => "Nostr Note",
+            Type::Profile => "Nostr Profile",
+            Type::Event => "Nostr Event",
+            Type::Address => "Nostr Address",
+        }
+    }
+
+    fn from_prefix(prefix: &str) -> Option<Type> {
+        match prefix {
+            "npub" => Some(Type::PublicKey),
+            "nsec" => Some(Type::SecretKey),
+            "note" => Some(Type::Note),
+            "nprofile" => Some(Type::Profile),
+            "nevent" => Some(Type::Event),
+            "naddr" => Some(Type::Address),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the TLV payload of `nprofile`, `nevent` and `naddr`
+fn parse_tlv(prefix: &str, entity_type: &Type, data: &[u8]) -> Option<NostrEntity> {
+    let mut entity = NostrEntity {
+        prefix: prefix.to_string(),
+        value: String::new(),
+        relays: vec![],
+        author: None,
+        kind: None,
+    };
+    let mut special = None;
+    let mut rest = data;
+    while !rest.is_empty() {
+        if rest.len() < 2 {
+            return None
+        }
+        let (tlv_type, length) = (rest[0], rest[1] as usize);
+        if rest.len() < 2 + length {
+            return None
+        }
+        let tlv_value = &rest[2..2 + length];
+        rest = &rest[2 + length..];
+        match tlv_type {
+            // only the first special entry counts
+            TLV_SPECIAL if special.is_none() => special = Some(tlv_value),
+            TLV_RELAY => entity.relays.push(String::from_utf8(tlv_value.to_vec()).ok()?),
+            TLV_AUTHOR => {
+                if length != 32 {
+                    return None
+                }
+                entity.author = Some(to_hex(tlv_value));
+            }
+            TLV_KIND => {
+                if length != 4 {
+                    return None
+                }
+                entity.kind = Some(u32::from_be_bytes([tlv_value[0], tlv_value[1], tlv_value[2], tlv_value[3]]));
+            }
+            // unknown types are ignored
+            _ => {}
+        }
+    }
+    let special = special?;
+    match entity_type {
+        Type::Address => {
+            // naddr needs the author and kind to address the event
+            if entity.author.is_none() || entity.kind.is_none() {
+                return None
+            }
+            entity.value = String::from_utf8(special.to_vec()).ok()?;
+        }
+        _ => {
+            if special.len() != 32 {
+                return None
+            }
+            entity.value = to_hex(special);
+        }
+    }
+    Some(entity)
+}
+
+pub fn decode_nostr(value: &str) -> Option<NostrEntity> {
+    //! Decode a NIP-19 bech32 entity (`npub`, `nsec`, `note`, `nprofile`, `nevent`, `naddr`).
+    //! The checksum, the 32 byte payloads and the TLV records are verified.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::decode_nostr;
+    //! fn main() {
+    //!     let entity = decode_nostr("npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg").unwrap();
+    //!     assert_eq!(entity.value, "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e");
+    //!     assert!(!entity.is_secret());
+    //! }
+    //! ```
+    let checked = CheckedHrpstring::new::<Bech32>(value).ok()?;
+    checked.validate_segwit_padding().ok()?;
+    let prefix = checked.hrp().to_lowercase();
+    let entity_type = Type::from_prefix(&prefix)?;
+    let data: Vec<u8> = checked.byte_iter().collect();
+    match entity_type {
+        Type::PublicKey | Type::SecretKey | Type::Note => {
+            if data.len() != 32 {
+                return None
+            }
+            Some(NostrEntity {
+                prefix,
+                value: to_hex(&data),
+                relays: vec![],
+                author: None,
+                kind: None,
+            })
+        }
+        _ => parse_tlv(&prefix, &entity_type, &data),
+    }
+}
+
+pub fn is_nostr(value: &str) -> bool {
+    //! Check if the given value is a valid NIP-19 entity.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_nostr;
+    //! fn main() {
+    //!     assert!(is_nostr("note1fntxtkcy9pjwucqwa9mddn7v03wwwsu9j330jj350nvhpky2tuaspk6nqc"));
+    //! }
+    //! ```
+    decode_nostr(value).is_some()
+}
+
+pub fn is_nostr_secret(value: &str) -> bool {
+    //! Check if the given value is a Nostr secret key (`nsec`).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_nostr_secret;
+    //! fn main() {
+    //!     assert!(is_nostr_secret("nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5"));
+    //! }
+    //! ```
+    match decode_nostr(value) {
+        Some(x) => x.is_secret(),
+        None => false,
+    }
+}
+
+pub fn which_nostr(value: &str) -> Option<&str> {
+    //! Output the NIP-19 entity type given a Nostr bech32 string.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::which_nostr;
+    //! fn main() {
+    //!     assert_eq!(
+    //!         which_nostr("npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg"),
+    //!         Some("Nostr Public Key")
+    //!     );
+    //! }
+    //! ```
+    let entity = decode_nostr(value)?;
+    Type::from_prefix(&entity.prefix).map(|x| x.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from NIP-19
+    const NPUB: &str = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+    const NSEC: &str = "nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5";
+    const NPROFILE: &str = "nprofile1qqsrhuxx8l9ex335q7he0f09aej04zpazpl0ne2cgukyawd24mayt8gpp4mhxue69uhhytnc9e3k7mgpz4mhxue69uhkg6nzv9ejuumpv34kytnrdaksjlyr9p";
+
+    #[test]
+    fn test_decode_nostr() {
+        let entity = decode_nostr(NSEC).unwrap();
+        assert_eq!(entity.value, "67dea2ed018072d675f5415ecfaed7d2597555e202d85b3d65ea4e58d2d92ffa");
+        assert!(entity.is_secret());
+
+        let entity = decode_nostr(NPROFILE).unwrap();
+        assert_eq!(entity.value, "3bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d");
+        assert_eq!(entity.relays, vec!["wss://r.x.com", "wss://djbas.sadkb.com"]);
+        assert_eq!(entity.author, None);
+
+        // bad checksum
+        assert!(decode_nostr("npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptf").is_none());
+        // unknown prefix
+        assert!(decode_nostr("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").is_none());
+    }
+
+    #[test]
+    fn test_parse_tlv() {
+        let mut data = vec![TLV_SPECIAL, 3];
+        data.extend_from_slice(b"abc");
+        data.extend_from_slice(&[TLV_RELAY, 9]);
+        data.extend_from_slice(b"wss://x.y");
+        data.extend_from_slice(&[TLV_AUTHOR, 32]);
+        data.extend_from_slice(&[0xab; 32]);
+        data.extend_from_slice(&[TLV_KIND, 4, 0, 0, 0x75, 0x33]);
+        // unknown record is skipped
+        data.extend_from_slice(&[9, 1, 0]);
+        let entity = parse_tlv("naddr", &Type::Address, &data).unwrap();
+        assert_eq!(entity.value, "abc");
+        assert_eq!(entity.relays, vec!["wss://x.y"]);
+        assert_eq!(entity.author, Some("ab".repeat(32)));
+        assert_eq!(entity.kind, Some(30003));
+
+        // naddr without kind
+        assert!(parse_tlv("naddr", &Type::Address, &data[..data.len() - 9]).is_none());
+        // nevent needs a 32 byte event id
+        assert!(parse_tlv("nevent", &Type::Event, &data).is_none());
+        // truncated record
+        assert!(parse_tlv("nevent", &Type::Event, &[TLV_SPECIAL, 32, 1]).is_none());
+    }
+
+    #[test]
+    fn test_is_nostr_secret() {
+        assert!(is_nostr_secret(NSEC));
+        assert!(!is_nostr_secret(NPUB));
+    }
+
+    #[test]
+    fn test_which_nostr() {
+        assert_eq!(which_nostr(NPUB), Some("Nostr Public Key"));
+        assert_eq!(which_nostr(NSEC), Some("Nostr Secret Key"));
+        assert_eq!(which_nostr(NPROFILE), Some("Nostr Profile"));
+        assert_eq!(which_nostr("npub1"), None);
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::{Arc, RwLock};
+
+use super::Type;
+
+lazy_static! {
+    /// Validators registered at runtime with `register_coin`
+    static ref CUSTOM: RwLock<Vec<Arc<dyn CoinValidator>>> = RwLock::new(vec![]);
+}
+
+/// A cryptocurrency address validator.
+///
+/// The built-in coins (`crypto::Type`) implement this trait; implement it for
+/// your own type and pass it to `register_coin` to have
+/// `which_cryptocurrency` and `is_cryptocurrency_any` recognize its addresses.
+pub trait CoinValidator: Send + Sync {
+    /// Name reported by `which_cryptocurrency`
+    fn name(&self) -> &'static str;
+
+    /// Check if the given value is an address of this coin
+    fn is_valid(&self, value: &str) -> bool;
+
+    /// Check if the given value can be told apart as an address of this coin
+    /// when the coin isn't known, as `which_cryptocurrency` does.
+    /// Defaults to `is_valid`.
+    fn is_detectable(&self, value: &str) -> bool {
+        self.is_valid(value)
+    }
+}
+
+pub fn register_coin<V: CoinValidator + 'static>(validator: V) {
+    //! Register a custom coin validator. Custom validators are consulted
+    //! before the built-in coins, in the order they were registered.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::{register_coin, which_cryptocurrency, CoinValidator};
+    //!
+    //! struct TestToken;
+    //!
+    //! impl CoinValidator for TestToken {
+    //!     fn name(&self) -> &'static str {
+    //!         "Test Token"
+    //!     }
+    //!
+    //!     fn is_valid(&self, value: &str) -> bool {
+    //!         value.starts_with("tt1") && value.len() == 20
+    //!     }
+    //! }
+    //!
+    //! fn main() {
+    //!     register_coin(TestToken);
+    //!     assert_eq!(which_cryptocurrency("tt1abcdefghijklmnopq"), Some("Test Token"));
+    //! }
+    //! ```
+    CUSTOM.write().unwrap().push(Arc::new(validator));
+}
+
+/// Custom validators followed by the built-in coins
+pub(super) fn validators() -> Vec<Arc<dyn CoinValidator>> {
+    let mut validators = CUSTOM.read().unwrap().clone();
+    for coin in Type::all() {
+        validators.push(Arc::new(coin));
+    }
+    validators
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Prefixed;
+
+    impl CoinValidator for Prefixed {
+        fn name(&self) -> &'static str {
+            "Prefixed Coin"
+        }
+
+        fn is_valid(&self, value: &str) -> bool {
+            value.starts_with("prefixed:")
+        }
+    }
+
+    #[test]
+    fn test_register_coin() {
+        register_coin(Prefixed);
+        let names: Vec<&str> = validators().iter().map(|x| x.name()).collect();
+        let custom = names.iter().position(|x| *x == "Prefixed Coin").unwrap();
+        let builtin = names.iter().position(|x| *x == "Bitcoin").unwrap();
+        // custom validators come first
+        assert!(custom < builtin);
+    }
+}
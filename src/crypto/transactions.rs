@@ -0,0 +1,206 @@
+use regex::Regex;
+
+use super::{CoinValidator, Type as Coin};
+
+lazy_static! {
+    /// Bare 256 bit hex digest (same shape as `hashes` SHA256)
+    static ref HEX256: Regex = Regex::new(r"^(?i)[0-9a-f]{64}$").unwrap();
+    /// `0x` prefixed 256 bit hex digest
+    static ref HEX256_PREFIXED: Regex = Regex::new(r"^0x(?i)[0-9a-f]{64}$").unwrap();
+    /// Base58 encoded 64 byte ed25519 signature
+    static ref BASE58_SIGNATURE: Regex = Regex::new(r"^[1-9A-HJ-NP-Za-km-z]{64,88}$").unwrap();
+}
+
+/// Transaction & Block identifier encodings
+enum Type {
+    Hex,
+    HexPrefixed,
+    Base58Signature,
+}
+
+impl Type {
+    /// Chains whose transaction or block identifiers share this encoding
+    fn chains<'a>(&self) -> Vec<&'a str> {
+        match *self {
+            Type::Hex => vec![
+                Coin::Bitcoin.name(),
+                Coin::BitcoinCash.name(),
+                Coin::Litecoin.name(),
+                Coin::Dodge.name(),
+                Coin::Dash.name(),
+                Coin::Monero.name(),
+                Coin::Ripple.name(),
+                "Tron",
+            ],
+            Type::HexPrefixed => vec![Coin::Ethereum.name(), Coin::Neo.name()],
+            Type::Base58Signature => vec!["Solana"],
+        }
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match *self {
+            Type::Hex => HEX256.is_match(value),
+            Type::HexPrefixed => HEX256_PREFIXED.is_match(value),
+            Type::Base58Signature => {
+                BASE58_SIGNATURE.is_match(value)
+                    && bs58::decode(value).into_vec().map(|x| x.len() == 64).unwrap_or(false)
+            }
+        }
+    }
+
+    fn all() -> Vec<Type> {
+        vec![Type::Hex, Type::HexPrefixed, Type::Base58Signature]
+    }
+}
+
+pub fn is_bitcoin_txid(value: &str) -> bool {
+    //! Check if the given value is a Bitcoin transaction id or block hash
+    //! (64 hex characters).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_bitcoin_txid;
+    //! fn main() {
+    //!     assert!(is_bitcoin_txid("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"));
+    //! }
+    //! ```
+    Type::Hex.is_match(value)
+}
+
+pub fn is_ethereum_tx_hash(value: &str) -> bool {
+    //! Check if the given value is an Ethereum transaction or block hash
+    //! (`0x` followed by 64 hex characters).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_ethereum_tx_hash;
+    //! fn main() {
+    //!     assert!(is_ethereum_tx_hash("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"));
+    //! }
+    //! ```
+    Type::HexPrefixed.is_match(value)
+}
+
+pub fn is_solana_signature(value: &str) -> bool {
+    //! Check if the given value is a Solana transaction signature
+    //! (base58 encoded 64 bytes).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_solana_signature;
+    //! fn main() {
+    //!     assert!(is_solana_signature("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"));
+    //! }
+    //! ```
+    Type::Base58Signature.is_match(value)
+}
+
+pub fn is_tron_tx_hash(value: &str) -> bool {
+    //! Check if the given value is a Tron transaction hash
+    //! (64 hex characters, no prefix).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_tron_tx_hash;
+    //! fn main() {
+    //!     assert!(is_tron_tx_hash("8a9e4a7d1f2c1e0b7e4c6a0c9b2f6d9e5e1c3b4a5d6e7f8091a2b3c4d5e6f708"));
+    //! }
+    //! ```
+    Type::Hex.is_match(value)
+}
+
+pub fn is_ripple_tx_hash(value: &str) -> bool {
+    //! Check if the given value is a Ripple (XRP Ledger) transaction hash
+    //! (64 hex characters, no prefix).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_ripple_tx_hash;
+    //! fn main() {
+    //!     assert!(is_ripple_tx_hash("E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7"));
+    //! }
+    //! ```
+    Type::Hex.is_match(value)
+}
+
+pub fn is_transaction_any(value: &str) -> bool {
+    //! Check if the given value is a transaction id or block hash of any known chain.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::is_transaction_any;
+    //! fn main() {
+    //!     assert!(!is_transaction_any("<transaction id>"));
+    //! }
+    //! ```
+    Type::all().iter().any(|x| x.is_match(value))
+}
+
+pub fn which_transaction(value: &str) -> Vec<&str> {
+    //! Output the candidate chains for a given transaction id or block hash.
+    //! Several chains share the same identifier shape (eg: 64 hex characters),
+    //! so every chain the value could belong to is returned.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::crypto::which_transaction;
+    //! fn main() {
+    //!     assert_eq!(
+    //!         which_transaction("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"),
+    //!         vec!["Ethereum", "Neo"]
+    //!     );
+    //! }
+    //! ```
+    for tx in Type::all() {
+        if tx.is_match(value) {
+            return tx.chains()
+        }
+    }
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_which_transaction() {
+        // Bitcoin genesis merkle root / txid
+        let candidates = which_transaction("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b");
+        assert!(candidates.contains(&"Bitcoin"));
+        assert!(candidates.contains(&"Tron"));
+        assert!(candidates.contains(&"Ripple"));
+        assert!(!candidates.contains(&"Ethereum"));
+        // Ethereum
+        assert_eq!(
+            which_transaction("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"),
+            vec!["Ethereum", "Neo"]
+        );
+        // Solana
+        assert_eq!(
+            which_transaction("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"),
+            vec!["Solana"]
+        );
+        // No chain identified
+        assert!(which_transaction("4a5e1e4baab89f3a32518a88c31bc87f").is_empty());
+    }
+
+    #[test]
+    fn test_is_transaction_any() {
+        assert!(is_transaction_any("E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7"));
+        assert!(!is_transaction_any("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b2206"));
+    }
+
+    #[test]
+    fn test_is_ethereum_tx_hash() {
+        assert!(is_ethereum_tx_hash("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"));
+        assert!(!is_ethereum_tx_hash("5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"));
+    }
+
+    #[test]
+    fn test_is_solana_signature() {
+        assert!(is_solana_signature("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"));
+        // 32 byte solana address, not a signature
+        assert!(!is_solana_signature("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"));
+    }
+}
@@ -1,21 +1,72 @@
 use regex::Regex;
 
+mod digest;
+mod fingerprints;
+mod fuzzy;
+mod git;
+mod integrity;
+mod manifest;
+mod multihash;
+mod normalize;
+mod strict;
+
+pub use digest::{compute_digest, verify_digest, verify_digest_with};
+pub use fingerprints::{
+    is_fingerprint, is_pgp_fingerprint, is_pgp_key_id, is_ssh_fingerprint, is_x509_thumbprint, parse_fingerprint,
+    which_fingerprint, Fingerprint,
+};
+use fuzzy::detect_fuzzy_hash;
+
+pub use fuzzy::{compare_ssdeep, diff_tlsh, is_sdhash, is_ssdeep, is_tlsh, which_fuzzy_hash};
+pub use git::{
+    is_git_abbreviated_id, is_git_describe, is_git_object_id, parse_git_describe, parse_git_object_id,
+    which_git_object_format, GitDescribe, GitObjectFormat, GitObjectId, GIT_MIN_ABBREV,
+};
+pub use integrity::{is_oci_digest, is_sri, parse_oci_digest, parse_sri, OciDigest, SriHash};
+pub use multihash::{decode_cid, decode_multihash, is_cid, is_multihash, Cid, Multihash};
+pub use normalize::{normalize_hash, NormalizedHash};
+pub use strict::{check_degenerate_hash, is_hash_any_strict, which_hash_strict, Degenerate};
+pub use manifest::{
+    parse_manifest, verify_manifest, Manifest, ManifestEntry, ManifestError, ManifestErrorKind, ManifestFormat,
+    Verification, VerifyStatus,
+};
+
 lazy_static! {
-    static ref MD5: Regex = Regex::new(r"^(?i)[0-9a-f]{32}$").unwrap();
-    static ref SHA1: Regex = Regex::new(r"^(?i)[0-9a-f]{40}$").unwrap();
-    static ref SHA224: Regex = Regex::new(r"^(?i)[0-9a-f]{56}$").unwrap();
-    static ref SHA256: Regex = Regex::new(r"^(?i)[0-9a-f]{64}$").unwrap();
-    static ref SHA384: Regex = Regex::new(r"^(?i)[0-9a-f]{96}$").unwrap();
-    static ref SHA512: Regex = Regex::new(r"^(?i)[0-9a-f]{128}$").unwrap();
+    static ref BITS_32: Regex = Regex::new(r"^(?i)[0-9a-f]{8}$").unwrap();
+    static ref BITS_64: Regex = Regex::new(r"^(?i)[0-9a-f]{16}$").unwrap();
+    static ref BITS_128: Regex = Regex::new(r"^(?i)[0-9a-f]{32}$").unwrap();
+    static ref BITS_160: Regex = Regex::new(r"^(?i)[0-9a-f]{40}$").unwrap();
+    static ref BITS_192: Regex = Regex::new(r"^(?i)[0-9a-f]{48}$").unwrap();
+    static ref BITS_224: Regex = Regex::new(r"^(?i)[0-9a-f]{56}$").unwrap();
+    static ref BITS_256: Regex = Regex::new(r"^(?i)[0-9a-f]{64}$").unwrap();
+    static ref BITS_384: Regex = Regex::new(r"^(?i)[0-9a-f]{96}$").unwrap();
+    static ref BITS_512: Regex = Regex::new(r"^(?i)[0-9a-f]{128}$").unwrap();
 }
 
-enum Type {
+/// Digest algorithms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
     MD5,
     SHA1,
     SHA224,
     SHA256,
     SHA384,
     SHA512,
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+    Keccak256,
+    BLAKE2b,
+    BLAKE2s,
+    BLAKE3,
+    RIPEMD160,
+    Whirlpool,
+    Tiger,
+    SM3,
+    CRC32,
+    XXH64,
+    XXH128,
 }
 
 impl Type {
@@ -27,20 +78,40 @@ impl Type {
             Type::SHA256 => "SHA256",
             Type::SHA384 => "SHA384",
             Type::SHA512 => "SHA512",
+            Type::SHA3_224 => "SHA3-224",
+            Type::SHA3_256 => "SHA3-256",
+            Type::SHA3_384 => "SHA3-384",
+            Type::SHA3_512 => "SHA3-512",
+            Type::Keccak256 => "Keccak256",
+            Type::BLAKE2b => "BLAKE2b",
+            Type::BLAKE2s => "BLAKE2s",
+            Type::BLAKE3 => "BLAKE3",
+            Type::RIPEMD160 => "RIPEMD160",
+            Type::Whirlpool => "Whirlpool",
+            Type::Tiger => "Tiger",
+            Type::SM3 => "SM3",
+            Type::CRC32 => "CRC32",
+            Type::XXH64 => "xxHash64",
+            Type::XXH128 => "xxHash128",
         }
     }
 
     fn pattern<'a>(&self) -> &'a Regex {
         match *self {
-            Type::MD5 => &MD5,
-            Type::SHA1 => &SHA1,
-            Type::SHA224 => &SHA224,
-            Type::SHA256 => &SHA256,
-            Type::SHA384 => &SHA384,
-            Type::SHA512 => &SHA512,
+            Type::CRC32 => &BITS_32,
+            Type::XXH64 => &BITS_64,
+            Type::MD5 | Type::XXH128 => &BITS_128,
+            Type::SHA1 | Type::RIPEMD160 => &BITS_160,
+            Type::Tiger => &BITS_192,
+            Type::SHA224 | Type::SHA3_224 => &BITS_224,
+            Type::SHA256 | Type::SHA3_256 | Type::Keccak256 | Type::BLAKE2s | Type::BLAKE3 | Type::SM3 => &BITS_256,
+            Type::SHA384 | Type::SHA3_384 => &BITS_384,
+            Type::SHA512 | Type::SHA3_512 | Type::BLAKE2b | Type::Whirlpool => &BITS_512,
         }
     }
 
+    /// Ordered by how common the digest is, so the first
+    /// candidate of a given length is the most likely one
     fn all() -> Vec<Type> {
         vec![
             Type::MD5,
@@ -49,18 +120,61 @@ impl Type {
             Type::SHA256,
             Type::SHA384,
             Type::SHA512,
+            Type::SHA3_224,
+            Type::SHA3_256,
+            Type::SHA3_384,
+            Type::SHA3_512,
+            Type::Keccak256,
+            Type::BLAKE2b,
+            Type::BLAKE2s,
+            Type::BLAKE3,
+            Type::RIPEMD160,
+            Type::Whirlpool,
+            Type::Tiger,
+            Type::SM3,
+            Type::CRC32,
+            Type::XXH64,
+            Type::XXH128,
         ]
     }
 }
 
+/// Decode a hex string, `None` if it isn't valid hex
+fn from_hex(value: &str) -> Option<Vec<u8>> {
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|x| match x {
+            [high, low] => Some((char::from(*high).to_digit(16)? * 16 + char::from(*low).to_digit(16)?) as u8),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Encode bytes as lowercase hex
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+/// Algorithm from its name, eg: `SHA256`, `sha-256`, `SHA3-256`, `BLAKE2b`, `SHA2-256` (openssl 3)
+fn algorithm_from_name(name: &str) -> Option<Type> {
+    let normalize = |x: &str| x.replace(['-', '_'], "").to_uppercase();
+    let name = normalize(name.strip_prefix("SHA2-").map(|x| format!("SHA{}", x)).as_deref().unwrap_or(name));
+    match name.as_str() {
+        "XXH64" => Some(Type::XXH64),
+        "XXH128" => Some(Type::XXH128),
+        _ => Type::all().into_iter().find(|x| normalize(x.name()) == name),
+    }
+}
+
 /// Evaluate Hash Type
 fn validate(value: &str) -> bool {
-    for hash in Type::all() {
-        if hash.pattern().is_match(&value) {
-            return true
-        }
-    }
-    false
+    !which_hash(value).is_empty()
+}
+
+/// Validate the raw Hash against a single Hash Type, without normalizing it
+fn validate_type(value: &str, hash: Type) -> bool {
+    hash.pattern().is_match(value)
 }
 
 pub fn is_md5(value: &str) -> bool {
@@ -74,7 +188,7 @@ pub fn is_md5(value: &str) -> bool {
     //!     assert_eq!(is_md5("<md5 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::MD5)
 }
 
 pub fn is_sha1(value: &str) -> bool {
@@ -88,7 +202,7 @@ pub fn is_sha1(value: &str) -> bool {
     //!     assert_eq!(is_sha1("<sha1 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::SHA1)
 }
 
 pub fn is_sha224(value: &str) -> bool {
@@ -102,7 +216,7 @@ pub fn is_sha224(value: &str) -> bool {
     //!     assert_eq!(is_sha224("<sha224 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::SHA224)
 }
 
 pub fn is_sha256(value: &str) -> bool {
@@ -116,7 +230,7 @@ pub fn is_sha256(value: &str) -> bool {
     //!     assert_eq!(is_sha256("<sha256 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::SHA256)
 }
 
 pub fn is_sha384(value: &str) -> bool {
@@ -130,7 +244,7 @@ pub fn is_sha384(value: &str) -> bool {
     //!     assert_eq!(is_sha384("<sha384 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::SHA384)
 }
 
 pub fn is_sha512(value: &str) -> bool {
@@ -144,41 +258,273 @@ pub fn is_sha512(value: &str) -> bool {
     //!     assert_eq!(is_sha512("<sha512 value>"), false)
     //! }
     //! ```
-    validate(value)
+    validate_type(value, Type::SHA512)
+}
+
+pub fn is_sha3_224(value: &str) -> bool {
+    //! Check if a given hash value is SHA3-224.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sha3_224;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_sha3_224("<sha3_224 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::SHA3_224)
+}
+
+pub fn is_sha3_256(value: &str) -> bool {
+    //! Check if a given hash value is SHA3-256.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sha3_256;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_sha3_256("<sha3_256 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::SHA3_256)
+}
+
+pub fn is_sha3_384(value: &str) -> bool {
+    //! Check if a given hash value is SHA3-384.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sha3_384;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_sha3_384("<sha3_384 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::SHA3_384)
+}
+
+pub fn is_sha3_512(value: &str) -> bool {
+    //! Check if a given hash value is SHA3-512.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sha3_512;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_sha3_512("<sha3_512 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::SHA3_512)
+}
+
+pub fn is_keccak256(value: &str) -> bool {
+    //! Check if a given hash value is Keccak256.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_keccak256;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_keccak256("<keccak256 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::Keccak256)
+}
+
+pub fn is_blake2b(value: &str) -> bool {
+    //! Check if a given hash value is BLAKE2b.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_blake2b;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_blake2b("<blake2b value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::BLAKE2b)
+}
+
+pub fn is_blake2s(value: &str) -> bool {
+    //! Check if a given hash value is BLAKE2s.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_blake2s;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_blake2s("<blake2s value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::BLAKE2s)
+}
+
+pub fn is_blake3(value: &str) -> bool {
+    //! Check if a given hash value is BLAKE3.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_blake3;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_blake3("<blake3 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::BLAKE3)
+}
+
+pub fn is_ripemd160(value: &str) -> bool {
+    //! Check if a given hash value is RIPEMD160.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_ripemd160;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_ripemd160("<ripemd160 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::RIPEMD160)
+}
+
+pub fn is_whirlpool(value: &str) -> bool {
+    //! Check if a given hash value is Whirlpool.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_whirlpool;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_whirlpool("<whirlpool value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::Whirlpool)
+}
+
+pub fn is_tiger(value: &str) -> bool {
+    //! Check if a given hash value is Tiger.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_tiger;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_tiger("<tiger value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::Tiger)
+}
+
+pub fn is_sm3(value: &str) -> bool {
+    //! Check if a given hash value is SM3.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sm3;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_sm3("<sm3 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::SM3)
+}
+
+pub fn is_crc32(value: &str) -> bool {
+    //! Check if a given hash value is CRC32.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_crc32;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_crc32("<crc32 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::CRC32)
+}
+
+pub fn is_xxhash64(value: &str) -> bool {
+    //! Check if a given hash value is xxHash64.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_xxhash64;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_xxhash64("<xxhash64 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::XXH64)
+}
+
+pub fn is_xxhash128(value: &str) -> bool {
+    //! Check if a given hash value is xxHash128.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_xxhash128;
+    //!
+    //! fn main() {
+    //!     assert_eq!(is_xxhash128("<xxhash128 value>"), false)
+    //! }
+    //! ```
+    validate_type(value, Type::XXH128)
 }
 
 pub fn is_hash_any(value: &str) -> bool {
     //! Check if a given value corresponds to a Hash Type.
+    //! Same as a non empty `which_hash`: unlike `is_sha256` and the other
+    //! `is_*` functions, prefixed or separated forms (`sha256:<hex>`) and
+    //! fuzzy hashes are accepted.
     //!
     //! ## Example Usage
     //! ```rust
-    //! use validaten::hashes::is_hash_any;
+    //! use validaten::hashes::{is_hash_any, is_md5};
     //!
     //! fn main() {
-    //!     assert_eq!(is_hash_any("<hash value>"), false)
+    //!     assert_eq!(is_hash_any("<hash value>"), false);
+    //!     assert!(is_hash_any("md5:5eb63bbbe01eeed093cb22bb8f5acdc3"));
+    //!     assert!(!is_md5("md5:5eb63bbbe01eeed093cb22bb8f5acdc3"));
     //! }
     //! ```
     validate(value)
 }
 
-pub fn which_hash(value: &str) -> Option<&str> {
+pub fn which_hash(value: &str) -> Vec<&str> {
     //! Check if a given value corresponds to a Hash Type
-    //! and outputs the appropriate Hash Name.
+    //! and outputs the candidate Hash Names.
+    //! Several digests share the same length (eg: SHA256, SHA3-256, BLAKE3),
+    //! so every candidate is reported, most common first.
+    //! Fuzzy hashes (ssdeep, TLSH, sdhash) are reported by their format name,
+    //! ssdeep hashes too short to tell apart from clock times (`12:30:45`)
+    //! are only recognized by `which_fuzzy_hash`.
+    //! The value is normalized first (see `normalize_hash`); an algorithm
+    //! prefix breaks the tie between digests of the same length.
     //!
     //! ## Example Usage
     //! ```rust
     //! use validaten::hashes::which_hash;
     //!
     //! fn main() {
-    //!     assert_eq!(which_hash("<hash value>"), None)
+    //!     assert!(which_hash("<hash value>").is_empty());
+    //!     assert_eq!(which_hash("5eb63bbbe01eeed093cb22bb8f5acdc3"), vec!["MD5", "xxHash128"]);
+    //!     assert_eq!(which_hash("md5:5eb63bbbe01eeed093cb22bb8f5acdc3"), vec!["MD5"]);
+    //!     assert_eq!(which_hash("3:hMCEfFkYwd:hMCEfFkY"), vec!["ssdeep"]);
+    //!     assert!(which_hash("12:30:45").is_empty());
     //! }
     //! ```
-    for hash in Type::all() {
-        if hash.pattern().is_match(&value) {
-            return Some(hash.name())
-        }
+    if let Some(x) = detect_fuzzy_hash(value) {
+        return vec![x]
     }
-    None
+    let normalized = match normalize_hash(value) {
+        Some(x) => x,
+        None => return vec![],
+    };
+    Type::all()
+        .into_iter()
+        .filter(|hash| hash.pattern().is_match(&normalized.value) && normalized.algorithm.unwrap_or(*hash) == *hash)
+        .map(|hash| hash.name())
+        .collect()
 }
 
 #[cfg(test)]
@@ -187,18 +533,44 @@ mod tests {
 
     #[test]
     fn test_which_hash() {
+        // crc32
+        assert_eq!(which_hash("cbf43926"), vec!["CRC32"]);
+        // clock times aren't ssdeep hashes
+        assert!(which_hash("12:30:45").is_empty());
+        assert!(which_hash("3:ab:cd").is_empty());
+        assert!(!is_hash_any("23:59:59"));
+        // xxhash64
+        assert_eq!(which_hash("44bc2cf5ad770999"), vec!["xxHash64"]);
         // md5
-        assert_eq!(which_hash("5eb63bbbe01eeed093cb22bb8f5acdc3"), Some("MD5"));
+        assert_eq!(which_hash("5eb63bbbe01eeed093cb22bb8f5acdc3"), vec!["MD5", "xxHash128"]);
         // sha1
-        assert_eq!(which_hash("2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED"), Some("SHA1"));
+        assert_eq!(which_hash("2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED"), vec!["SHA1", "RIPEMD160"]);
+        // tiger
+        assert_eq!(which_hash("3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"), vec!["Tiger"]);
         // sha224
-        assert_eq!(which_hash("2f05477fc24bb4faefd86517156dafdecec45b8ad3cf2522a563582b"), Some("SHA224"));
+        assert_eq!(which_hash("2f05477fc24bb4faefd86517156dafdecec45b8ad3cf2522a563582b"), vec!["SHA224", "SHA3-224"]);
         // sha256
-        assert_eq!(which_hash("B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9"), Some("SHA256"));
+        assert_eq!(
+            which_hash("B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9"),
+            vec!["SHA256", "SHA3-256", "Keccak256", "BLAKE2s", "BLAKE3", "SM3"]
+        );
         // sha384
-        assert_eq!(which_hash("fdbd8e75a67f29f701a4e040385e2e23986303ea10239211af907fcbb83578b3e417cb71ce646efd0819dd8c088de1bd"), Some("SHA384"));
+        assert_eq!(which_hash("fdbd8e75a67f29f701a4e040385e2e23986303ea10239211af907fcbb83578b3e417cb71ce646efd0819dd8c088de1bd"), vec!["SHA384", "SHA3-384"]);
         // sha512
-        assert_eq!(which_hash("309ECC489C12D6EB4CC40F50C902F2B4D0ED77EE511A7C7A9BCD3CA86D4CD86F989DD35BC5FF499670DA34255B45B0CFD830E81F605DCF7DC5542E93AE9CD76F"), Some("SHA512"));
+        assert_eq!(
+            which_hash("309ECC489C12D6EB4CC40F50C902F2B4D0ED77EE511A7C7A9BCD3CA86D4CD86F989DD35BC5FF499670DA34255B45B0CFD830E81F605DCF7DC5542E93AE9CD76F"),
+            vec!["SHA512", "SHA3-512", "BLAKE2b", "Whirlpool"]
+        );
+        // pasted forms
+        assert_eq!(which_hash("0x5EB63BBBE01EEED093CB22BB8F5ACDC3"), vec!["MD5", "xxHash128"]);
+        assert_eq!(which_hash("sha3-224: 2f05477fc24bb4faefd86517156dafdecec45b8ad3cf2522a563582b"), vec!["SHA3-224"]);
+        assert_eq!(which_hash("BLAKE3=b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"), vec!["BLAKE3"]);
+        assert_eq!(which_hash("2A:AE:6C:35:C9:4F:CF:B4:15:DB:E9:5F:40:8B:9C:E9:1E:E8:46:ED"), vec!["SHA1", "RIPEMD160"]);
+        // the prefix contradicts the length
+        assert!(which_hash("sha256:5eb63bbbe01eeed093cb22bb8f5acdc3").is_empty());
+        // not a hash
+        assert!(which_hash("3f21").is_empty());
+        assert!(which_hash("zz4d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcdzz").is_empty());
     }
 
     #[test]
@@ -207,9 +579,54 @@ mod tests {
         assert!(is_hash_any("5eb63bbbe01eeed093cb22bb8f5acdc3"));
     }
 
+    #[test]
+    fn test_is_hash_type_by_length() {
+        // in the order of Type::all()
+        let checks: [fn(&str) -> bool; 21] = [
+            is_md5,
+            is_sha1,
+            is_sha224,
+            is_sha256,
+            is_sha384,
+            is_sha512,
+            is_sha3_224,
+            is_sha3_256,
+            is_sha3_384,
+            is_sha3_512,
+            is_keccak256,
+            is_blake2b,
+            is_blake2s,
+            is_blake3,
+            is_ripemd160,
+            is_whirlpool,
+            is_tiger,
+            is_sm3,
+            is_crc32,
+            is_xxhash64,
+            is_xxhash128,
+        ];
+        let hashes = Type::all();
+        assert_eq!(hashes.len(), checks.len());
+        let samples: Vec<String> = hashes
+            .iter()
+            .map(|x| compute_digest(&b"hello world"[..], *x).unwrap())
+            .collect();
+        // every sample must only be accepted by the hash functions of its length
+        for (i, sample) in samples.iter().enumerate() {
+            assert!(checks[i](sample), "{} against its own check", hashes[i].name());
+            assert!(checks[i](&sample.to_uppercase()), "{} uppercase", hashes[i].name());
+            for (j, check) in checks.iter().enumerate() {
+                let same_length = sample.len() == samples[j].len();
+                assert_eq!(check(sample), same_length, "{} against {}", hashes[i].name(), hashes[j].name());
+            }
+        }
+    }
+
     #[test]
     fn test_is_md5() {
         assert!(is_md5("5eb63bbbe01eeed093cb22bb8f5acdc3"));
+        // pasted forms are only accepted by which_hash
+        assert!(!is_md5(" MD5:5EB63BBBE01EEED093CB22BB8F5ACDC3 "));
     }
 
     #[test]
@@ -236,4 +653,25 @@ mod tests {
     fn test_is_sha512() {
         assert!(is_sha512("309ECC489C12D6EB4CC40F50C902F2B4D0ED77EE511A7C7A9BCD3CA86D4CD86F989DD35BC5FF499670DA34255B45B0CFD830E81F605DCF7DC5542E93AE9CD76F"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_is_hash_same_length() {
+        // shape-identical digests are all accepted, other lengths are not
+        let sha3_256 = "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a";
+        assert!(is_sha3_256(sha3_256));
+        assert!(is_keccak256(sha3_256));
+        assert!(is_blake2s(sha3_256));
+        assert!(is_blake3(sha3_256));
+        assert!(is_sm3(sha3_256));
+        assert!(!is_sha3_224(sha3_256));
+        assert!(!is_sha3_384(sha3_256));
+        assert!(!is_sha3_512(sha3_256));
+        assert!(!is_blake2b(sha3_256));
+        assert!(!is_whirlpool(sha3_256));
+        assert!(!is_tiger(sha3_256));
+        assert!(!is_ripemd160(sha3_256));
+        assert!(!is_crc32(sha3_256));
+        assert!(!is_xxhash64(sha3_256));
+        assert!(!is_xxhash128(sha3_256));
+    }
+}
@@ -0,0 +1,259 @@
+use std::hash::Hasher as _;
+use std::io::{self, Read};
+
+use sha2::digest::DynDigest;
+use subtle::ConstantTimeEq;
+
+use super::{from_hex, normalize_hash, to_hex, NormalizedHash, Type};
+
+/// Size of the chunks read from the input
+const CHUNK_SIZE: usize = 64 * 1024;
+
+const NOT_HEX: &str = "expected value is not a hex digest";
+
+/// Streaming state of a single digest algorithm
+enum Hasher {
+    Digest(Box<dyn DynDigest>),
+    Blake3(Box<blake3::Hasher>),
+    Crc32(crc32fast::Hasher),
+    Xxh64(twox_hash::XxHash64),
+    Xxh128(Box<twox_hash::XxHash3_128>),
+}
+
+impl Hasher {
+    fn new(hash: Type) -> Hasher {
+        match hash {
+            Type::MD5 => Hasher::Digest(Box::new(md5::Md5::default())),
+            Type::SHA1 => Hasher::Digest(Box::new(sha1::Sha1::default())),
+            Type::SHA224 => Hasher::Digest(Box::new(sha2::Sha224::default())),
+            Type::SHA256 => Hasher::Digest(Box::new(sha2::Sha256::default())),
+            Type::SHA384 => Hasher::Digest(Box::new(sha2::Sha384::default())),
+            Type::SHA512 => Hasher::Digest(Box::new(sha2::Sha512::default())),
+            Type::SHA3_224 => Hasher::Digest(Box::new(sha3::Sha3_224::default())),
+            Type::SHA3_256 => Hasher::Digest(Box::new(sha3::Sha3_256::default())),
+            Type::SHA3_384 => Hasher::Digest(Box::new(sha3::Sha3_384::default())),
+            Type::SHA3_512 => Hasher::Digest(Box::new(sha3::Sha3_512::default())),
+            Type::Keccak256 => Hasher::Digest(Box::new(sha3::Keccak256::default())),
+            Type::BLAKE2b => Hasher::Digest(Box::new(blake2::Blake2b512::default())),
+            Type::BLAKE2s => Hasher::Digest(Box::new(blake2::Blake2s256::default())),
+            Type::BLAKE3 => Hasher::Blake3(Box::default()),
+            Type::RIPEMD160 => Hasher::Digest(Box::new(ripemd::Ripemd160::default())),
+            Type::Whirlpool => Hasher::Digest(Box::new(whirlpool::Whirlpool::default())),
+            Type::Tiger => Hasher::Digest(Box::new(tiger::Tiger::default())),
+            Type::SM3 => Hasher::Digest(Box::new(sm3::Sm3::default())),
+            Type::CRC32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            Type::XXH64 => Hasher::Xxh64(twox_hash::XxHash64::with_seed(0)),
+            Type::XXH128 => Hasher::Xxh128(Box::default()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Digest(x) => x.update(data),
+            Hasher::Blake3(x) => {
+                x.update(data);
+            }
+            Hasher::Crc32(x) => x.update(data),
+            Hasher::Xxh64(x) => x.write(data),
+            Hasher::Xxh128(x) => x.write(data),
+        }
+    }
+
+    /// Digest bytes in their canonical (big endian) order
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Digest(x) => x.finalize().to_vec(),
+            Hasher::Blake3(x) => x.finalize().as_bytes().to_vec(),
+            Hasher::Crc32(x) => x.finalize().to_be_bytes().to_vec(),
+            Hasher::Xxh64(x) => x.finish().to_be_bytes().to_vec(),
+            Hasher::Xxh128(x) => x.finish_128().to_be_bytes().to_vec(),
+        }
+    }
+}
+
+/// Stream the input once through every given algorithm
+fn digest_all<R: Read>(mut reader: R, hashes: &[Type]) -> io::Result<Vec<Vec<u8>>> {
+    let mut hashers: Vec<Hasher> = hashes.iter().map(|x| Hasher::new(*x)).collect();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(x) => x,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for hasher in hashers.iter_mut() {
+            hasher.update(&buffer[..read]);
+        }
+    }
+    Ok(hashers.into_iter().map(|x| x.finalize()).collect())
+}
+
+/// Parse the expected digest, rejecting values that aren't hex
+fn parse_expected(expected: &str) -> io::Result<NormalizedHash> {
+    normalize_hash(expected).ok_or_else(|| invalid_input(NOT_HEX))
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Verify the input against the candidate algorithms,
+/// comparing the digests in constant time
+fn verify<R: Read>(reader: R, expected: &str, hashes: Vec<Type>) -> io::Result<bool> {
+    let expected = from_hex(expected).ok_or_else(|| invalid_input(NOT_HEX))?;
+    if hashes.is_empty() {
+        return Ok(false)
+    }
+    let digests = digest_all(reader, &hashes)?;
+    // check every candidate so the time taken doesn't depend on which one matched
+    let matched = digests
+        .iter()
+        .fold(0u8, |matched, digest| matched | digest.ct_eq(&expected).unwrap_u8());
+    Ok(matched == 1)
+}
+
+pub fn compute_digest<R: Read>(reader: R, hash: Type) -> io::Result<String> {
+    //! Compute the digest of the data read from `reader`
+    //! as lowercase hex.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{compute_digest, Type};
+    //!
+    //! fn main() {
+    //!     assert_eq!(
+    //!         compute_digest(&b"hello world"[..], Type::MD5).unwrap(),
+    //!         "5eb63bbbe01eeed093cb22bb8f5acdc3"
+    //!     );
+    //! }
+    //! ```
+    let digests = digest_all(reader, &[hash])?;
+    Ok(to_hex(&digests[0]))
+}
+
+pub fn verify_digest<R: Read>(reader: R, expected: &str) -> io::Result<bool> {
+    //! Verify the data read from `reader` against an expected hex digest.
+    //! The algorithm is detected from the expected value; when several
+    //! algorithms share its length (eg: SHA256, SHA3-256, BLAKE3), the input
+    //! is streamed once through all of them and any match is accepted.
+    //! Prefixed and separated forms (`sha256:<hex>`, `0x<hex>`, `ab:cd:...`)
+    //! are normalized first, an algorithm prefix restricts the candidates.
+    //! The comparison is done in constant time. An expected value that isn't
+    //! a digest of any known algorithm is an `io::ErrorKind::InvalidInput` error.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::verify_digest;
+    //!
+    //! fn main() {
+    //!     let expected = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+    //!     assert!(verify_digest(&b"hello world"[..], expected).unwrap());
+    //!     assert!(!verify_digest(&b"hello world!"[..], expected).unwrap());
+    //! }
+    //! ```
+    let expected = parse_expected(expected)?;
+    let hashes: Vec<Type> = Type::all()
+        .into_iter()
+        .filter(|hash| hash.pattern().is_match(&expected.value))
+        .filter(|hash| expected.algorithm.unwrap_or(*hash) == *hash)
+        .collect();
+    if hashes.is_empty() {
+        return Err(invalid_input("expected value is not a digest of a known algorithm"))
+    }
+    verify(reader, &expected.value, hashes)
+}
+
+pub fn verify_digest_with<R: Read>(reader: R, expected: &str, hash: Type) -> io::Result<bool> {
+    //! Verify the data read from `reader` against an expected hex digest
+    //! of the given algorithm, normalized like in `verify_digest`. A digest
+    //! of the wrong length or prefixed with another algorithm doesn't match.
+    //! The comparison is done in constant time. An expected value that isn't
+    //! hex is an `io::ErrorKind::InvalidInput` error.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{verify_digest_with, Type};
+    //!
+    //! fn main() {
+    //!     let expected = "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed";
+    //!     assert!(verify_digest_with(&b"hello world"[..], expected, Type::SHA1).unwrap());
+    //!     assert!(!verify_digest_with(&b"hello world"[..], expected, Type::RIPEMD160).unwrap());
+    //! }
+    //! ```
+    let expected = parse_expected(expected)?;
+    if !hash.pattern().is_match(&expected.value) || expected.algorithm.is_some_and(|x| x != hash) {
+        return Ok(false)
+    }
+    verify(reader, &expected.value, vec![hash])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = b"hello world";
+
+    #[test]
+    fn test_compute_digest() {
+        let vectors = [
+            (Type::MD5, "5eb63bbbe01eeed093cb22bb8f5acdc3"),
+            (Type::SHA1, "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"),
+            (Type::SHA256, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"),
+            (Type::SHA3_256, "644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938"),
+            (Type::Keccak256, "47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad"),
+            (Type::BLAKE2s, "9aec6806794561107e594b1f6a8a6b0c92a0cba9acf5e5e93cca06f781813b0b"),
+            (Type::BLAKE3, "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24"),
+            (Type::RIPEMD160, "98c615784ccb5fe5936fbc0cbe9dfdb408d92f0f"),
+            (Type::CRC32, "0d4a1185"),
+            (Type::XXH64, "45ab6734b21e6968"),
+        ];
+        for (hash, expected) in vectors.iter() {
+            assert_eq!(&compute_digest(INPUT, *hash).unwrap(), expected, "{}", hash.name());
+        }
+    }
+
+    #[test]
+    fn test_compute_digest_chunked() {
+        // larger than a single chunk
+        let data = vec![b'a'; CHUNK_SIZE * 2 + 7];
+        let expected = compute_digest(&data[..], Type::SHA256).unwrap();
+        assert!(verify_digest(&data[..], &expected).unwrap());
+        assert!(!verify_digest(&data[1..], &expected).unwrap());
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        // uppercase and surrounding whitespace
+        assert!(verify_digest(INPUT, " B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9\n").unwrap());
+        // shares its length with SHA256
+        assert!(verify_digest(INPUT, "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24").unwrap());
+        assert!(verify_digest(INPUT, "0d4a1185").unwrap());
+        // prefixed and separated forms
+        assert!(verify_digest(INPUT, "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9").unwrap());
+        assert!(verify_digest(INPUT, "0x0D4A1185").unwrap());
+        assert!(verify_digest(INPUT, "5e:b6:3b:bb:e0:1e:ee:d0:93:cb:22:bb:8f:5a:cd:c3").unwrap());
+        // the prefix rules out BLAKE3
+        assert!(!verify_digest(INPUT, "sha256:d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24").unwrap());
+        // not a digest
+        for expected in ["not a digest", "", "abc", "sha256:5eb63bbbe01eeed093cb22bb8f5acdc3"].iter() {
+            let error = verify_digest(INPUT, expected).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_digest_with() {
+        let sha256 = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+        assert!(verify_digest_with(INPUT, sha256, Type::SHA256).unwrap());
+        assert!(!verify_digest_with(INPUT, sha256, Type::BLAKE3).unwrap());
+        // wrong length for the algorithm
+        assert!(!verify_digest_with(INPUT, sha256, Type::SHA512).unwrap());
+        assert!(verify_digest_with(INPUT, &format!("SHA256={}", sha256), Type::SHA256).unwrap());
+        assert!(!verify_digest_with(INPUT, &format!("sha3-256:{}", sha256), Type::SHA256).unwrap());
+        assert_eq!(
+            verify_digest_with(INPUT, "not a digest", Type::SHA256).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
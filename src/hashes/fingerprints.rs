@@ -0,0 +1,305 @@
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use base64::Engine;
+use regex::Regex;
+
+use super::{from_hex, to_hex, Type as Digest};
+
+lazy_static! {
+    /// OpenSSH `SHA256:` and unpadded base64 of 32 bytes
+    static ref SSH_SHA256: Regex = Regex::new(r"^SHA256:([A-Za-z0-9+/]{43})$").unwrap();
+    /// OpenSSH legacy MD5: 16 colon separated lowercase hex pairs, optional `MD5:` prefix
+    static ref SSH_MD5: Regex = Regex::new(r"^(MD5:)?([0-9a-f]{2}(:[0-9a-f]{2}){15})$").unwrap();
+    /// PGP fingerprint, bare or in 4 character groups as printed by gpg
+    static ref PGP_FINGERPRINT: Regex = Regex::new(r"^(0x)?(?i)([0-9a-f]{4}( {1,2})?)+$").unwrap();
+    static ref PGP_KEY_ID: Regex = Regex::new(r"^(0x)?(?i)([0-9a-f]{8}|[0-9a-f]{16})$").unwrap();
+    /// X.509 thumbprint: hex pairs separated by `:` or spaces
+    static ref X509_THUMBPRINT: Regex = Regex::new(r"^(?i)[0-9a-f]{2}([: ][0-9a-f]{2})+$").unwrap();
+}
+
+/// Key & certificate fingerprint formats
+enum Type {
+    SshSha256,
+    SshMd5,
+    PgpV4,
+    PgpV5,
+    PgpLongKeyId,
+    PgpShortKeyId,
+    X509Sha1,
+    X509Sha256,
+}
+
+impl Type {
+    fn name(&self) -> &'static str {
+        match *self {
+            Type::SshSha256 => "SSH SHA256",
+            Type::SshMd5 => "SSH MD5",
+            Type::PgpV4 => "PGP v4 Fingerprint",
+            Type::PgpV5 => "PGP v5 Fingerprint",
+            Type::PgpLongKeyId => "PGP Long Key ID",
+            Type::PgpShortKeyId => "PGP Short Key ID",
+            Type::X509Sha1 => "X.509 SHA1 Thumbprint",
+            Type::X509Sha256 => "X.509 SHA256 Thumbprint",
+        }
+    }
+
+    /// Digest algorithm of the fingerprint, `None` for key ids
+    /// which are only the tail of a fingerprint
+    fn algorithm(&self) -> Option<Digest> {
+        match *self {
+            Type::SshSha256 | Type::PgpV5 | Type::X509Sha256 => Some(Digest::SHA256),
+            Type::SshMd5 => Some(Digest::MD5),
+            Type::PgpV4 | Type::X509Sha1 => Some(Digest::SHA1),
+            Type::PgpLongKeyId | Type::PgpShortKeyId => None,
+        }
+    }
+
+    /// Decoded fingerprint bytes, if the value is of this type
+    fn decode(&self, value: &str) -> Option<Vec<u8>> {
+        match *self {
+            Type::SshSha256 => {
+                let captures = SSH_SHA256.captures(value)?;
+                STANDARD_NO_PAD.decode(&captures[1]).ok()
+            }
+            Type::SshMd5 => from_hex(&SSH_MD5.captures(value)?[2].replace(':', "")),
+            Type::PgpV4 | Type::PgpV5 => {
+                if !PGP_FINGERPRINT.is_match(value) {
+                    return None
+                }
+                let bytes = from_hex(&value.trim_start_matches("0x").replace(' ', ""))?;
+                let length = if let Type::PgpV4 = *self { 20 } else { 32 };
+                Some(bytes).filter(|x| x.len() == length)
+            }
+            Type::PgpLongKeyId | Type::PgpShortKeyId => {
+                if !PGP_KEY_ID.is_match(value) {
+                    return None
+                }
+                let bytes = from_hex(value.trim_start_matches("0x"))?;
+                let length = if let Type::PgpLongKeyId = *self { 8 } else { 4 };
+                Some(bytes).filter(|x| x.len() == length)
+            }
+            Type::X509Sha1 | Type::X509Sha256 => {
+                // a single kind of separator throughout
+                if !X509_THUMBPRINT.is_match(value) || (value.contains(':') && value.contains(' ')) {
+                    return None
+                }
+                let bytes = from_hex(&value.replace([':', ' '], ""))?;
+                let length = if let Type::X509Sha1 = *self { 20 } else { 32 };
+                Some(bytes).filter(|x| x.len() == length)
+            }
+        }
+    }
+
+    /// Same as `decode`, minus bare hex PGP values that can't be told apart
+    /// from a SHA1 / SHA256 / CRC32 digest: the `0x` prefix or the gpg
+    /// 4 character groups are required
+    fn decode_detectable(&self, value: &str) -> Option<Vec<u8>> {
+        match *self {
+            Type::PgpV4 | Type::PgpV5 if !value.starts_with("0x") && !value.contains(' ') => None,
+            Type::PgpLongKeyId | Type::PgpShortKeyId if !value.starts_with("0x") => None,
+            _ => self.decode(value),
+        }
+    }
+
+    fn all() -> Vec<Type> {
+        vec![
+            Type::SshSha256,
+            Type::SshMd5,
+            Type::PgpV4,
+            Type::PgpV5,
+            Type::PgpLongKeyId,
+            Type::PgpShortKeyId,
+            Type::X509Sha1,
+            Type::X509Sha256,
+        ]
+    }
+}
+
+/// A parsed key or certificate fingerprint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// Fingerprint format, eg: `SSH SHA256`, `PGP v4 Fingerprint`
+    pub name: &'static str,
+    /// Digest algorithm, `None` for PGP key ids
+    pub algorithm: Option<Digest>,
+    /// Fingerprint bytes, lowercase hex
+    pub digest: String,
+}
+
+pub fn parse_fingerprint(value: &str) -> Option<Fingerprint> {
+    //! Parse an OpenSSH (`SHA256:<base64>`, colon separated MD5), PGP
+    //! (v4: 40 hex, v5: 64 hex, long & short key ids, optionally `0x`
+    //! prefixed or in 4 character groups) or X.509 (colon or space separated
+    //! SHA1 / SHA256 hex pairs) fingerprint. Bare hex is a plain digest as
+    //! much as a PGP fingerprint, PGP values need the `0x` prefix or gpg's
+    //! 4 character groups; `is_pgp_fingerprint` & `is_pgp_key_id` accept both.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::parse_fingerprint;
+    //!
+    //! fn main() {
+    //!     let fingerprint = parse_fingerprint("SHA256:uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek").unwrap();
+    //!     assert_eq!(fingerprint.name, "SSH SHA256");
+    //!     assert_eq!(fingerprint.digest, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::all().into_iter().find_map(|x| {
+        x.decode_detectable(value).map(|bytes| Fingerprint {
+            name: x.name(),
+            algorithm: x.algorithm(),
+            digest: to_hex(&bytes),
+        })
+    })
+}
+
+pub fn is_fingerprint(value: &str) -> bool {
+    //! Check if a given value is an SSH, PGP or X.509 fingerprint.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_fingerprint;
+    //!
+    //! fn main() {
+    //!     assert!(is_fingerprint("16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48"));
+    //!     assert!(!is_fingerprint("16:27:ac"));
+    //! }
+    //! ```
+    parse_fingerprint(value).is_some()
+}
+
+pub fn which_fingerprint(value: &str) -> Option<&str> {
+    //! Check if a given value is a fingerprint and outputs its format name.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::which_fingerprint;
+    //!
+    //! fn main() {
+    //!     assert_eq!(which_fingerprint("0xA1B2C3D4E5F60718"), Some("PGP Long Key ID"));
+    //! }
+    //! ```
+    parse_fingerprint(value).map(|x| x.name)
+}
+
+pub fn is_ssh_fingerprint(value: &str) -> bool {
+    //! Check if a given value is an OpenSSH key fingerprint (SHA256 or legacy MD5).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_ssh_fingerprint;
+    //!
+    //! fn main() {
+    //!     assert!(is_ssh_fingerprint("MD5:16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48"));
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::SshSha256.decode(value).is_some() || Type::SshMd5.decode(value).is_some()
+}
+
+pub fn is_pgp_fingerprint(value: &str) -> bool {
+    //! Check if a given value is a PGP v4 (40 hex) or v5 (64 hex) key fingerprint.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_pgp_fingerprint;
+    //!
+    //! fn main() {
+    //!     assert!(is_pgp_fingerprint("7C1A 8B5E 1E7A 5C0B 3E0D  9C7B 2F0B 5D0C 8A6D 4E2F"));
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::PgpV4.decode(value).is_some() || Type::PgpV5.decode(value).is_some()
+}
+
+pub fn is_pgp_key_id(value: &str) -> bool {
+    //! Check if a given value is a PGP long (16 hex) or short (8 hex) key id.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_pgp_key_id;
+    //!
+    //! fn main() {
+    //!     assert!(is_pgp_key_id("0x8A6D4E2F"));
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::PgpLongKeyId.decode(value).is_some() || Type::PgpShortKeyId.decode(value).is_some()
+}
+
+pub fn is_x509_thumbprint(value: &str) -> bool {
+    //! Check if a given value is an X.509 certificate SHA1 or SHA256 thumbprint
+    //! in colon or space separated hex pairs.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_x509_thumbprint;
+    //!
+    //! fn main() {
+    //!     assert!(is_x509_thumbprint("2A:AE:6C:35:C9:4F:CF:B4:15:DB:E9:5F:40:8B:9C:E9:1E:E8:46:ED"));
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::X509Sha1.decode(value).is_some() || Type::X509Sha256.decode(value).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_which_fingerprint() {
+        assert_eq!(which_fingerprint("SHA256:uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek"), Some("SSH SHA256"));
+        assert_eq!(which_fingerprint("16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48"), Some("SSH MD5"));
+        assert_eq!(which_fingerprint("7C1A 8B5E 1E7A 5C0B 3E0D  9C7B 2F0B 5D0C 8A6D 4E2F"), Some("PGP v4 Fingerprint"));
+        assert_eq!(which_fingerprint("0x7C1A8B5E1E7A5C0B3E0D9C7B2F0B5D0C8A6D4E2F"), Some("PGP v4 Fingerprint"));
+        assert_eq!(
+            which_fingerprint("0x19347BC87B1C5F0CB5A8A0E1B3D1D7D64E3F8D2C9A4B6E0F1D2C3B4A59687766"),
+            Some("PGP v5 Fingerprint")
+        );
+        assert_eq!(which_fingerprint("0x8A6D4E2F"), Some("PGP Short Key ID"));
+        assert_eq!(
+            which_fingerprint("b9 4d 27 b9 93 4d 3e 08 a5 2e 52 d7 da 7d ab fa c4 84 ef e3 7a 53 80 ee 90 88 f7 ac e2 ef cd e9"),
+            Some("X.509 SHA256 Thumbprint")
+        );
+        assert_eq!(which_fingerprint("2a:ae:6c:35:c9:4f:cf:b4:15:db:e9:5f:40:8b:9c:e9:1e:e8:46:ed"), Some("X.509 SHA1 Thumbprint"));
+        assert_eq!(which_fingerprint("not a fingerprint"), None);
+        // bare SHA1, SHA256, CRC32 & xxHash64 digests
+        assert_eq!(which_fingerprint("2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"), None);
+        assert_eq!(which_fingerprint("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"), None);
+        assert_eq!(which_fingerprint("cbf43926"), None);
+        assert_eq!(which_fingerprint("44bc2cf5ad770999"), None);
+        assert!(!is_fingerprint("cbf43926"));
+    }
+
+    #[test]
+    fn test_is_ssh_fingerprint() {
+        // padded, wrong length, uppercase MD5
+        assert!(!is_ssh_fingerprint("SHA256:uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="));
+        assert!(!is_ssh_fingerprint("SHA256:uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvze"));
+        assert!(!is_ssh_fingerprint("16:27:AC:A5:76:28:2D:36:63:1B:56:4D:EB:DF:A6:48"));
+    }
+
+    #[test]
+    fn test_is_pgp_fingerprint() {
+        assert!(is_pgp_fingerprint("7c1a 8b5e 1e7a 5c0b 3e0d 9c7b 2f0b 5d0c 8a6d 4e2f"));
+        assert!(is_pgp_fingerprint("7C1A8B5E1E7A5C0B3E0D9C7B2F0B5D0C8A6D4E2F"));
+        assert!(!is_pgp_fingerprint("7C1A 8B5E 1E7A 5C0B 3E0D  9C7B 2F0B 5D0C 8A6D"));
+        assert!(!is_pgp_fingerprint("7C1A8B5E1E7A5C0B3E0D9C7B2F0B5D0C8A6D4E2"));
+    }
+
+    #[test]
+    fn test_is_pgp_key_id() {
+        assert!(is_pgp_key_id("2F0B5D0C8A6D4E2F"));
+        assert!(!is_pgp_key_id("0x8A6D4E2"));
+        assert!(!is_pgp_key_id("8A6D4E2F8A"));
+    }
+
+    #[test]
+    fn test_is_x509_thumbprint() {
+        assert!(is_x509_thumbprint("2A AE 6C 35 C9 4F CF B4 15 DB E9 5F 40 8B 9C E9 1E E8 46 ED"));
+        // mixed separators, MD5 length
+        assert!(!is_x509_thumbprint("2A:AE 6C:35:C9:4F:CF:B4:15:DB:E9:5F:40:8B:9C:E9:1E:E8:46:ED"));
+        assert!(!is_x509_thumbprint("16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48"));
+    }
+}
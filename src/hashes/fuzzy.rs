@@ -0,0 +1,408 @@
+use regex::Regex;
+
+use super::from_hex;
+
+lazy_static! {
+    /// ssdeep `blocksize:hash:hash`, optionally followed by `,"filename"`
+    static ref SSDEEP: Regex = Regex::new(r#"^([0-9]+):([A-Za-z0-9+/]{0,64}):([A-Za-z0-9+/]{0,64})(,".*")?$"#).unwrap();
+    /// TLSH version 1: `T1` and 70 hex characters
+    static ref TLSH: Regex = Regex::new(r"^T1(?i)[0-9a-f]{70}$").unwrap();
+    /// sdhash `sdbf` / `sdbf-dd` header: `sdbf:03:namelen:name:size:sha1:...`
+    static ref SDHASH: Regex = Regex::new(r"^sdbf(-dd)?:[0-9]{2}:([0-9]+):").unwrap();
+}
+
+/// Smallest ssdeep block size, every block size is this times a power of two
+const SSDEEP_MIN_BLOCKSIZE: u64 = 3;
+
+/// Longest ssdeep signature part
+const SSDEEP_LENGTH: usize = 64;
+
+/// Length of the rolling hash window, the shortest substring ssdeep matches on
+const SSDEEP_ROLLING_WINDOW: usize = 7;
+
+/// Fuzzy (similarity preserving) hash formats
+enum Type {
+    Ssdeep,
+    Tlsh,
+    Sdhash,
+}
+
+impl Type {
+    fn name<'a>(&self) -> &'a str {
+        match *self {
+            Type::Ssdeep => "ssdeep",
+            Type::Tlsh => "TLSH",
+            Type::Sdhash => "sdhash",
+        }
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match *self {
+            Type::Ssdeep => parse_ssdeep(value).is_some(),
+            Type::Tlsh => TLSH.is_match(value),
+            Type::Sdhash => is_sdhash_header(value),
+        }
+    }
+
+    /// Same as `is_match`, minus the short ssdeep hashes that look like
+    /// clock times and other `n:x:y` tokens
+    fn is_detectable(&self, value: &str) -> bool {
+        match *self {
+            Type::Ssdeep => is_ssdeep_detectable(value),
+            _ => self.is_match(value),
+        }
+    }
+
+    fn all() -> Vec<Type> {
+        vec![Type::Ssdeep, Type::Tlsh, Type::Sdhash]
+    }
+}
+
+/// Block size and both signature parts of an ssdeep hash
+fn parse_ssdeep(value: &str) -> Option<(u64, &str, &str)> {
+    let captures = SSDEEP.captures(value)?;
+    let blocksize = captures[1].parse::<u64>().ok()?;
+    // 3·2^n
+    if blocksize % SSDEEP_MIN_BLOCKSIZE != 0 || !(blocksize / SSDEEP_MIN_BLOCKSIZE).is_power_of_two() {
+        return None
+    }
+    let (first, second) = (captures.get(2)?.as_str(), captures.get(3)?.as_str());
+    Some((blocksize, first, second))
+}
+
+/// ssdeep only settles on a block size above the minimum once the first part
+/// is at least half the signature long; at the minimum block size the first
+/// part must still span a rolling hash window
+fn is_ssdeep_detectable(value: &str) -> bool {
+    match parse_ssdeep(value) {
+        Some((blocksize, first, _)) if blocksize == SSDEEP_MIN_BLOCKSIZE => first.len() >= SSDEEP_ROLLING_WINDOW,
+        Some((_, first, _)) => first.len() >= SSDEEP_LENGTH / 2,
+        None => false,
+    }
+}
+
+/// The name in the header is length prefixed and may contain `:`
+fn is_sdhash_header(value: &str) -> bool {
+    let captures = match SDHASH.captures(value) {
+        Some(x) => x,
+        None => return false,
+    };
+    let length = match captures[2].parse::<usize>() {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    let rest = &value[captures[0].len()..];
+    if rest.len() < length || !rest.is_char_boundary(length) {
+        return false
+    }
+    let mut fields = rest[length..].split(':');
+    // empty field after the name, then the file size & the bloom filter hash
+    matches!(
+        (fields.next(), fields.next(), fields.next()),
+        (Some(""), Some(size), Some("sha1")) if !size.is_empty() && size.chars().all(|x| x.is_ascii_digit())
+    )
+}
+
+/// Reduce runs of more than 3 identical characters to 3, they carry no information
+fn eliminate_sequences(value: &str) -> Vec<u8> {
+    let mut reduced: Vec<u8> = Vec::with_capacity(value.len());
+    for byte in value.bytes() {
+        let len = reduced.len();
+        if len >= 3 && reduced[len - 1] == byte && reduced[len - 2] == byte && reduced[len - 3] == byte {
+            continue
+        }
+        reduced.push(byte);
+    }
+    reduced
+}
+
+/// Edit distance with insertion & deletion costing 1 and substitution 2
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + if x == y { 0 } else { 2 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn has_common_substring(a: &[u8], b: &[u8]) -> bool {
+    a.windows(SSDEEP_ROLLING_WINDOW)
+        .any(|x| b.windows(SSDEEP_ROLLING_WINDOW).any(|y| x == y))
+}
+
+/// Similarity of two signature parts computed with the same block size
+fn score_strings(a: &[u8], b: &[u8], blocksize: u64) -> u32 {
+    if a.len() > SSDEEP_LENGTH || b.len() > SSDEEP_LENGTH || !has_common_substring(a, b) {
+        return 0
+    }
+    let distance = (edit_distance(a, b) * SSDEEP_LENGTH / (a.len() + b.len())) * 100 / SSDEEP_LENGTH;
+    if distance >= 100 {
+        return 0
+    }
+    let score = (100 - distance) as u32;
+    // small block sizes can't produce long matches, cap their score
+    let threshold = (99 + SSDEEP_ROLLING_WINDOW as u64) / SSDEEP_ROLLING_WINDOW as u64 * SSDEEP_MIN_BLOCKSIZE;
+    if blocksize >= threshold {
+        return score
+    }
+    let cap = (blocksize / SSDEEP_MIN_BLOCKSIZE) as usize * a.len().min(b.len());
+    score.min(cap as u32)
+}
+
+/// Checksum, length, quartile ratios & body of a TLSH digest
+fn parse_tlsh(value: &str) -> Option<(u8, u8, u8, u8, Vec<u8>)> {
+    if !TLSH.is_match(value) {
+        return None
+    }
+    let bytes = from_hex(&value[2..])?;
+    // header bytes are stored with their nibbles swapped
+    let swap = |x: u8| x.rotate_left(4);
+    let (checksum, length, ratios) = (swap(bytes[0]), swap(bytes[1]), swap(bytes[2]));
+    Some((checksum, length, ratios & 0x0f, ratios >> 4, bytes[3..].to_vec()))
+}
+
+/// Distance between two values on a circular range
+fn mod_diff(x: u8, y: u8, range: u32) -> u32 {
+    let (x, y) = (u32::from(x), u32::from(y));
+    let direct = x.abs_diff(y);
+    direct.min(range - direct)
+}
+
+pub fn is_ssdeep(value: &str) -> bool {
+    //! Check if a given value is an ssdeep fuzzy hash:
+    //! `blocksize:hash:hash` with a block size of 3·2^n.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_ssdeep;
+    //!
+    //! fn main() {
+    //!     assert!(is_ssdeep("96:s4Ud1Lj96tHHlZDrwciQmA+4uy1I0G4HYuL8N3TzS8QsO/wqWXLcMSx:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO"));
+    //!     assert!(!is_ssdeep("97:s4Ud1Lj96tHHlZDrwciQmA+4uy1I0G4HYuL8N3TzS8QsO/wqWXLcMSx:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO"));
+    //! }
+    //! ```
+    Type::Ssdeep.is_match(value)
+}
+
+pub fn is_tlsh(value: &str) -> bool {
+    //! Check if a given value is a TLSH digest: `T1` followed by 70 hex characters.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_tlsh;
+    //!
+    //! fn main() {
+    //!     assert!(is_tlsh("T1A8E02B2A3DE83E17A5F3D0C8E0A5B3B1C9F3D2E1A4B5C6D7E8F9A0B1C2D3E4F5A6B7C8"));
+    //! }
+    //! ```
+    Type::Tlsh.is_match(value)
+}
+
+pub fn is_sdhash(value: &str) -> bool {
+    //! Check if a given value starts with an sdhash (`sdbf` or `sdbf-dd`) header.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sdhash;
+    //!
+    //! fn main() {
+    //!     assert!(is_sdhash("sdbf:03:8:file.bin:1024:sha1:256:5:7ff:160:1:100:AAAA"));
+    //! }
+    //! ```
+    Type::Sdhash.is_match(value)
+}
+
+pub fn which_fuzzy_hash(value: &str) -> Option<&str> {
+    //! Check if a given value corresponds to a fuzzy hash format
+    //! and outputs its name.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::which_fuzzy_hash;
+    //!
+    //! fn main() {
+    //!     assert_eq!(which_fuzzy_hash("3:hMCE:hMCE"), Some("ssdeep"));
+    //! }
+    //! ```
+    Type::all().into_iter().find(|x| x.is_match(value)).map(|x| x.name())
+}
+
+/// Fuzzy hash format `which_hash` reports: short ssdeep hashes are left out
+/// as they can't be told apart from clock times (`12:30:45`)
+pub(super) fn detect_fuzzy_hash(value: &str) -> Option<&str> {
+    Type::all().into_iter().find(|x| x.is_detectable(value)).map(|x| x.name())
+}
+
+pub fn compare_ssdeep(a: &str, b: &str) -> Option<u32> {
+    //! Compare two ssdeep hashes and output their similarity score
+    //! from 0 (no similarity) to 100 (identical), like `ssdeep -d`.
+    //! Hashes whose block sizes are too far apart score 0.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::compare_ssdeep;
+    //!
+    //! fn main() {
+    //!     let a = "96:s4Ud1Lj96tHHlZDrwciQmA+4uy1I0G4HYuL8N3TzS8QsO/wqWXLcMSx:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO";
+    //!     assert_eq!(compare_ssdeep(a, a), Some(100));
+    //!     assert_eq!(compare_ssdeep(a, "not ssdeep"), None);
+    //! }
+    //! ```
+    let (blocksize_a, a1, a2) = parse_ssdeep(a)?;
+    let (blocksize_b, b1, b2) = parse_ssdeep(b)?;
+    // ssdeep only ever emits half a signature for the doubled block size
+    let (a2, b2) = (
+        &a2[..a2.len().min(SSDEEP_LENGTH / 2)],
+        &b2[..b2.len().min(SSDEEP_LENGTH / 2)],
+    );
+    let (a1, a2, b1, b2) = (
+        eliminate_sequences(a1),
+        eliminate_sequences(a2),
+        eliminate_sequences(b1),
+        eliminate_sequences(b2),
+    );
+    let score = if blocksize_a == blocksize_b {
+        if a1 == b1 && a2 == b2 {
+            return Some(100)
+        }
+        score_strings(&a1, &b1, blocksize_a).max(score_strings(&a2, &b2, blocksize_a * 2))
+    } else if blocksize_a == blocksize_b * 2 {
+        score_strings(&a1, &b2, blocksize_a)
+    } else if blocksize_b == blocksize_a * 2 {
+        score_strings(&a2, &b1, blocksize_b)
+    } else {
+        0
+    };
+    Some(score)
+}
+
+pub fn diff_tlsh(a: &str, b: &str) -> Option<u32> {
+    //! Compare two TLSH digests and output their distance:
+    //! 0 for identical digests, growing as the inputs differ
+    //! (values under ~100 are usually considered similar).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::diff_tlsh;
+    //!
+    //! fn main() {
+    //!     let a = "T1A8E02B2A3DE83E17A5F3D0C8E0A5B3B1C9F3D2E1A4B5C6D7E8F9A0B1C2D3E4F5A6B7C8";
+    //!     assert_eq!(diff_tlsh(a, a), Some(0));
+    //! }
+    //! ```
+    let (checksum_a, length_a, q1_a, q2_a, body_a) = parse_tlsh(a)?;
+    let (checksum_b, length_b, q1_b, q2_b, body_b) = parse_tlsh(b)?;
+    let mut diff = match mod_diff(length_a, length_b, 256) {
+        x if x <= 1 => x,
+        x => x * 12,
+    };
+    for (x, y) in [(q1_a, q1_b), (q2_a, q2_b)].iter() {
+        diff += match mod_diff(*x, *y, 16) {
+            d if d <= 1 => d,
+            d => (d - 1) * 12,
+        };
+    }
+    if checksum_a != checksum_b {
+        diff += 1;
+    }
+    // each byte holds 4 buckets of 2 bits, opposite buckets are penalized
+    for (x, y) in body_a.iter().zip(body_b.iter()) {
+        for shift in (0..8).step_by(2) {
+            diff += match ((x >> shift) & 0b11, (y >> shift) & 0b11) {
+                (x, y) if x == y => 0,
+                (0, 3) | (3, 0) => 6,
+                (x, y) => u32::from(x.abs_diff(y)),
+            };
+        }
+    }
+    Some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SSDEEP_A: &str = "96:s4Ud1Lj96tHHlZDrwciQmA+4uy1I0G4HYuL8N3TzS8QsO/wqWXLcMSx:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO";
+    const TLSH_A: &str = "T1A8E02B2A3DE83E17A5F3D0C8E0A5B3B1C9F3D2E1A4B5C6D7E8F9A0B1C2D3E4F5A6B7C8";
+
+    #[test]
+    fn test_which_fuzzy_hash() {
+        assert_eq!(which_fuzzy_hash(SSDEEP_A), Some("ssdeep"));
+        assert_eq!(which_fuzzy_hash(&format!("{},\"/tmp/file.bin\"", SSDEEP_A)), Some("ssdeep"));
+        assert_eq!(which_fuzzy_hash("3::"), Some("ssdeep"));
+        assert_eq!(which_fuzzy_hash(TLSH_A), Some("TLSH"));
+        assert_eq!(
+            which_fuzzy_hash("sdbf:03:9:a:b:c.bin:1024:sha1:256:5:7ff:160:1:100:AAAA"),
+            Some("sdhash")
+        );
+        // block size isn't 3·2^n
+        assert_eq!(which_fuzzy_hash("9:hMCE:hMCE"), None);
+        assert_eq!(which_fuzzy_hash("0:hMCE:hMCE"), None);
+        // TLSH without the version or too short
+        assert_eq!(which_fuzzy_hash(&TLSH_A[2..]), None);
+        assert_eq!(which_fuzzy_hash(&TLSH_A[..70]), None);
+        // name shorter than its declared length
+        assert_eq!(which_fuzzy_hash("sdbf:03:99:a.bin:1024:sha1:"), None);
+    }
+
+    #[test]
+    fn test_detect_fuzzy_hash() {
+        assert_eq!(detect_fuzzy_hash(SSDEEP_A), Some("ssdeep"));
+        assert_eq!(detect_fuzzy_hash("3:hMCEfFkYwd:hMCEfFkY"), Some("ssdeep"));
+        assert_eq!(detect_fuzzy_hash(TLSH_A), Some("TLSH"));
+        // clock times and short `n:x:y` tokens
+        assert_eq!(detect_fuzzy_hash("12:30:45"), None);
+        assert_eq!(detect_fuzzy_hash("24:00:00"), None);
+        assert_eq!(detect_fuzzy_hash("3:ab:cd"), None);
+        assert_eq!(detect_fuzzy_hash("3::"), None);
+        // above the minimum block size the first part is at least 32 characters
+        assert_eq!(detect_fuzzy_hash("96:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RW:xyz"), None);
+        assert_eq!(detect_fuzzy_hash("96:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO:xyz"), Some("ssdeep"));
+    }
+
+    #[test]
+    fn test_eliminate_sequences() {
+        assert_eq!(eliminate_sequences("aaaaabbbc"), b"aaabbbc".to_vec());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 5);
+        assert_eq!(edit_distance(b"", b"abc"), 3);
+    }
+
+    #[test]
+    fn test_compare_ssdeep() {
+        let b = "96:s4Ud1Lj96tHHlZDrwciQmA+4uy1I0G4HYuL8N3TzS8QsO/wqWXLcMSy:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWP";
+        let score = compare_ssdeep(SSDEEP_A, b).unwrap();
+        assert!(score > 90 && score < 100, "{}", score);
+        // block sizes 96 & 192 are compared on their common part
+        assert_eq!(compare_ssdeep(SSDEEP_A, "192:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO:xyz"), Some(100));
+        // block sizes too far apart
+        assert_eq!(compare_ssdeep(SSDEEP_A, "384:sF1LjEtHHlZDrJzrhuyZvHYm8tKp/RWO:xyz"), Some(0));
+        assert_eq!(compare_ssdeep("3:abcdefgh:abc", "3:zyxwvuts:zyx"), Some(0));
+        assert_eq!(compare_ssdeep("3:abc:abc", "3:abc:abc"), Some(100));
+        // identical first parts alone aren't a full match
+        assert_eq!(compare_ssdeep("3:abc:abc", "3:abc:xyz"), Some(0));
+        // the second part is compared on its first 32 characters
+        let long = format!("{}{}", SSDEEP_A, "x".repeat(10));
+        assert_eq!(compare_ssdeep(SSDEEP_A, &long), Some(100));
+    }
+
+    #[test]
+    fn test_diff_tlsh() {
+        assert_eq!(diff_tlsh(TLSH_A, TLSH_A), Some(0));
+        // only the checksum differs
+        let checksum = format!("T1B8{}", &TLSH_A[4..]);
+        assert_eq!(diff_tlsh(TLSH_A, &checksum), Some(1));
+        // a single body bucket 0 against 3
+        let body = format!("{}B", &TLSH_A[..71]);
+        assert_eq!(diff_tlsh(TLSH_A, &body), Some(6));
+        assert_eq!(diff_tlsh(TLSH_A, "T1"), None);
+    }
+}
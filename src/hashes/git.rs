@@ -0,0 +1,231 @@
+use regex::Regex;
+
+lazy_static! {
+    static ref OBJECT_ID: Regex = Regex::new(r"^(?i)[0-9a-f]+$").unwrap();
+    /// `<tag>-<commits since tag>-g<abbreviated id>[-dirty|-broken]`
+    static ref DESCRIBE: Regex = Regex::new(r"^(.+)-([0-9]+)-g([0-9a-fA-F]+)(-dirty|-broken)?$").unwrap();
+}
+
+/// Shortest abbreviation git accepts (`core.abbrev` minimum)
+pub const GIT_MIN_ABBREV: usize = 4;
+
+/// Object format (hash algorithm) of a git repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitObjectFormat {
+    Sha1,
+    Sha256,
+}
+
+impl GitObjectFormat {
+    /// Length of a full object id in hex characters
+    fn hex_length(&self) -> usize {
+        match *self {
+            GitObjectFormat::Sha1 => 40,
+            GitObjectFormat::Sha256 => 64,
+        }
+    }
+}
+
+/// A full or abbreviated git object name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitObjectId {
+    /// Object id, lowercase hex
+    pub id: String,
+    /// Object format the repository needs, `None` if the id fits both
+    pub format: Option<GitObjectFormat>,
+    pub abbreviated: bool,
+}
+
+/// Parsed `git describe` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitDescribe {
+    /// Nearest tag
+    pub tag: String,
+    /// Number of commits on top of the tag
+    pub distance: u32,
+    /// Abbreviated id of the described commit
+    pub object: GitObjectId,
+    /// `-dirty` or `-broken` suffix
+    pub dirty: bool,
+}
+
+pub fn parse_git_object_id(value: &str, min_length: usize) -> Option<GitObjectId> {
+    //! Parse a full (SHA-1: 40 hex, SHA-256: 64 hex) or abbreviated git object id.
+    //! Abbreviations must be at least `min_length` characters (never less than 4).
+    //! The object format is reported when the length requires one: full ids,
+    //! and abbreviations longer than 40 characters which only SHA-256 can have.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{parse_git_object_id, GitObjectFormat};
+    //!
+    //! fn main() {
+    //!     let id = parse_git_object_id("e83c5163316f89bfbde7d9ab23ca2e25604af290", 7).unwrap();
+    //!     assert_eq!(id.format, Some(GitObjectFormat::Sha1));
+    //!     assert!(!id.abbreviated);
+    //!     assert_eq!(parse_git_object_id("e83c5163", 7).unwrap().format, None);
+    //!     assert!(parse_git_object_id("e83c5", 7).is_none());
+    //! }
+    //! ```
+    if value.len() < min_length.max(GIT_MIN_ABBREV) || !OBJECT_ID.is_match(value) {
+        return None
+    }
+    let (format, abbreviated) = match value.len() {
+        x if x == GitObjectFormat::Sha1.hex_length() => (Some(GitObjectFormat::Sha1), false),
+        x if x == GitObjectFormat::Sha256.hex_length() => (Some(GitObjectFormat::Sha256), false),
+        x if x < GitObjectFormat::Sha1.hex_length() => (None, true),
+        x if x < GitObjectFormat::Sha256.hex_length() => (Some(GitObjectFormat::Sha256), true),
+        _ => return None,
+    };
+    Some(GitObjectId {
+        id: value.to_lowercase(),
+        format,
+        abbreviated,
+    })
+}
+
+pub fn is_git_object_id(value: &str) -> bool {
+    //! Check if a given value is a full git object id: SHA-1 (40 hex) or SHA-256 (64 hex).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_git_object_id;
+    //!
+    //! fn main() {
+    //!     assert!(is_git_object_id("e83c5163316f89bfbde7d9ab23ca2e25604af290"));
+    //!     assert!(!is_git_object_id("e83c516"));
+    //! }
+    //! ```
+    match parse_git_object_id(value, GIT_MIN_ABBREV) {
+        Some(x) => !x.abbreviated,
+        None => false,
+    }
+}
+
+pub fn is_git_abbreviated_id(value: &str, min_length: usize) -> bool {
+    //! Check if a given value is an abbreviated git object id
+    //! of at least `min_length` characters (never less than 4).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_git_abbreviated_id;
+    //!
+    //! fn main() {
+    //!     assert!(is_git_abbreviated_id("e83c516", 7));
+    //!     assert!(!is_git_abbreviated_id("e83c", 7));
+    //! }
+    //! ```
+    match parse_git_object_id(value, min_length) {
+        Some(x) => x.abbreviated,
+        None => false,
+    }
+}
+
+pub fn which_git_object_format(value: &str) -> Option<GitObjectFormat> {
+    //! Output the object format a repository needs to resolve a given full
+    //! or abbreviated object id, `None` if it isn't an object id or fits both.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{which_git_object_format, GitObjectFormat};
+    //!
+    //! fn main() {
+    //!     assert_eq!(
+    //!         which_git_object_format("8a4ee2e1b59d0e3a7e1c8c8d7f3b2b5f1e6f2d1c0b9a8f7e6d5c4b3a29180706"),
+    //!         Some(GitObjectFormat::Sha256)
+    //!     );
+    //! }
+    //! ```
+    parse_git_object_id(value, GIT_MIN_ABBREV).and_then(|x| x.format)
+}
+
+pub fn parse_git_describe(value: &str) -> Option<GitDescribe> {
+    //! Parse `git describe` output: `<tag>-<commits>-g<abbreviated id>`,
+    //! with an optional `-dirty` or `-broken` suffix.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::parse_git_describe;
+    //!
+    //! fn main() {
+    //!     let describe = parse_git_describe("v1.2-14-gabc1234-dirty").unwrap();
+    //!     assert_eq!(describe.tag, "v1.2");
+    //!     assert_eq!(describe.distance, 14);
+    //!     assert_eq!(describe.object.id, "abc1234");
+    //!     assert!(describe.dirty);
+    //! }
+    //! ```
+    let captures = DESCRIBE.captures(value)?;
+    Some(GitDescribe {
+        tag: captures[1].to_string(),
+        distance: captures[2].parse().ok()?,
+        object: parse_git_object_id(&captures[3], GIT_MIN_ABBREV)?,
+        dirty: captures.get(4).is_some(),
+    })
+}
+
+pub fn is_git_describe(value: &str) -> bool {
+    //! Check if a given value is `git describe` output.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_git_describe;
+    //!
+    //! fn main() {
+    //!     assert!(is_git_describe("v1.2-14-gabc1234"));
+    //!     assert!(!is_git_describe("v1.2"));
+    //! }
+    //! ```
+    parse_git_describe(value).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA1: &str = "e83c5163316f89bfbde7d9ab23ca2e25604af290";
+    const SHA256: &str = "8a4ee2e1b59d0e3a7e1c8c8d7f3b2b5f1e6f2d1c0b9a8f7e6d5c4b3a29180706";
+
+    #[test]
+    fn test_parse_git_object_id() {
+        let id = parse_git_object_id(&SHA256.to_uppercase(), 7).unwrap();
+        assert_eq!(id.id, SHA256);
+        assert_eq!(id.format, Some(GitObjectFormat::Sha256));
+        // minimum length is never below 4
+        assert!(parse_git_object_id("e83", 0).is_none());
+        assert!(parse_git_object_id("e83c", 0).is_some());
+        // too long, not hex
+        assert!(parse_git_object_id(&format!("{}0", SHA256), 7).is_none());
+        assert!(parse_git_object_id("e83c516g", 7).is_none());
+    }
+
+    #[test]
+    fn test_which_git_object_format() {
+        assert_eq!(which_git_object_format(SHA1), Some(GitObjectFormat::Sha1));
+        assert_eq!(which_git_object_format(SHA256), Some(GitObjectFormat::Sha256));
+        // abbreviation longer than a SHA-1 id
+        assert_eq!(which_git_object_format(&SHA256[..50]), Some(GitObjectFormat::Sha256));
+        assert_eq!(which_git_object_format(&SHA1[..12]), None);
+        assert_eq!(which_git_object_format("HEAD"), None);
+    }
+
+    #[test]
+    fn test_is_git_abbreviated_id() {
+        assert!(is_git_abbreviated_id(&SHA1[..7], 7));
+        assert!(is_git_abbreviated_id(&SHA1[..39], 7));
+        assert!(!is_git_abbreviated_id(SHA1, 7));
+        assert!(!is_git_abbreviated_id(&SHA1[..6], 7));
+    }
+
+    #[test]
+    fn test_parse_git_describe() {
+        let describe = parse_git_describe("release-2024-03-0-g8a4ee2e1b").unwrap();
+        assert_eq!(describe.tag, "release-2024-03");
+        assert_eq!(describe.distance, 0);
+        assert!(!describe.dirty);
+        assert!(is_git_describe(&format!("v2.0-1-g{}-broken", SHA256)));
+        assert!(!is_git_describe("v1.2-14-gabc"));
+        assert!(!is_git_describe("v1.2-x-gabc1234"));
+        assert!(!is_git_describe("-14-gabc1234"));
+    }
+}
@@ -0,0 +1,202 @@
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use regex::Regex;
+
+use super::{to_hex, Type};
+
+lazy_static! {
+    /// SRI `hash-expression`: `algorithm-base64[?options]`
+    static ref SRI_TOKEN: Regex = Regex::new(r"^([a-z0-9]+)-([A-Za-z0-9+/]+={0,2})((\?[\x21-\x7e]*)*)$").unwrap();
+    /// OCI `algorithm:encoded`
+    static ref OCI_DIGEST: Regex = Regex::new(r"^([a-z0-9]+([+._-][a-z0-9]+)*):([a-zA-Z0-9=_-]+)$").unwrap();
+    static ref OCI_HEX_256: Regex = Regex::new(r"^[a-f0-9]{64}$").unwrap();
+    static ref OCI_HEX_512: Regex = Regex::new(r"^[a-f0-9]{128}$").unwrap();
+}
+
+/// Standard alphabet, with or without the trailing padding
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// A single hash of a Subresource Integrity `integrity` attribute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SriHash {
+    pub algorithm: Type,
+    /// Decoded digest, lowercase hex
+    pub digest: String,
+    /// Options following the digest (`?opt`), reserved by the spec
+    pub options: Vec<String>,
+}
+
+/// An OCI / Docker content digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciDigest {
+    pub algorithm: Type,
+    /// Encoded digest, lowercase hex
+    pub digest: String,
+}
+
+/// Algorithms allowed in an `integrity` attribute and their digest sizes in bytes
+fn sri_algorithm(name: &str) -> Option<(Type, usize)> {
+    match name {
+        "sha256" => Some((Type::SHA256, 32)),
+        "sha384" => Some((Type::SHA384, 48)),
+        "sha512" => Some((Type::SHA512, 64)),
+        _ => None,
+    }
+}
+
+/// Algorithms of the OCI image-spec registry and their encoded form
+fn oci_algorithm<'a>(name: &str) -> Option<(Type, &'a Regex)> {
+    match name {
+        "sha256" => Some((Type::SHA256, &OCI_HEX_256)),
+        "sha512" => Some((Type::SHA512, &OCI_HEX_512)),
+        "blake3" => Some((Type::BLAKE3, &OCI_HEX_256)),
+        _ => None,
+    }
+}
+
+pub fn parse_sri(value: &str) -> Option<Vec<SriHash>> {
+    //! Parse a Subresource Integrity `integrity` attribute:
+    //! whitespace separated `sha256-`, `sha384-` or `sha512-` base64 digests,
+    //! each optionally followed by `?options`. Every token must use a supported
+    //! algorithm and decode to the exact digest length.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{parse_sri, Type};
+    //!
+    //! fn main() {
+    //!     let hashes = parse_sri("sha256-uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek=").unwrap();
+    //!     assert_eq!(hashes[0].algorithm, Type::SHA256);
+    //!     assert_eq!(hashes[0].digest, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+    //! }
+    //! ```
+    let hashes: Option<Vec<SriHash>> = value
+        .split_ascii_whitespace()
+        .map(|token| {
+            let captures = SRI_TOKEN.captures(token)?;
+            let (algorithm, size) = sri_algorithm(&captures[1])?;
+            let digest = BASE64.decode(&captures[2]).ok()?;
+            if digest.len() != size {
+                return None
+            }
+            let options = captures[3].split('?').skip(1).map(|x| x.to_string()).collect();
+            Some(SriHash {
+                algorithm,
+                digest: to_hex(&digest),
+                options,
+            })
+        })
+        .collect();
+    hashes.filter(|x| !x.is_empty())
+}
+
+pub fn is_sri(value: &str) -> bool {
+    //! Check if a given value is a valid Subresource Integrity `integrity` attribute.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_sri;
+    //!
+    //! fn main() {
+    //!     assert!(is_sri("sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"));
+    //!     assert!(!is_sri("md5-XrY7u+Ae7tCTyyK7j1rNww=="));
+    //! }
+    //! ```
+    parse_sri(value).is_some()
+}
+
+pub fn parse_oci_digest(value: &str) -> Option<OciDigest> {
+    //! Parse an OCI / Docker content digest (`algorithm:encoded`).
+    //! Only the registered algorithms are accepted: `sha256` and `blake3`
+    //! (64 lowercase hex) and `sha512` (128 lowercase hex).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{parse_oci_digest, Type};
+    //!
+    //! fn main() {
+    //!     let digest = parse_oci_digest("sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b").unwrap();
+    //!     assert_eq!(digest.algorithm, Type::SHA256);
+    //! }
+    //! ```
+    let captures = OCI_DIGEST.captures(value)?;
+    let (algorithm, encoded) = oci_algorithm(&captures[1])?;
+    let digest = &captures[3];
+    if !encoded.is_match(digest) {
+        return None
+    }
+    Some(OciDigest {
+        algorithm,
+        digest: digest.to_string(),
+    })
+}
+
+pub fn is_oci_digest(value: &str) -> bool {
+    //! Check if a given value is a valid OCI / Docker content digest.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_oci_digest;
+    //!
+    //! fn main() {
+    //!     assert!(is_oci_digest("sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b"));
+    //!     assert!(!is_oci_digest("sha256:6C3C624B58DBBCD3C0DD82B4C53F04194D1247C6EEBDAAB7C610CF7D66709B3B"));
+    //! }
+    //! ```
+    parse_oci_digest(value).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sri() {
+        // multiple tokens, options & no padding
+        let hashes = parse_sri(
+            " sha256-uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek?ct=application/javascript\n\
+             sha512-MJ7MSJwS1utMxA9QyQLytNDtd+5RGnx6m808qG1M2G+YndNbxf9JlnDaNCVbRbDP2DDoH2Bdz33FVC6TrpzXbw==",
+        )
+        .unwrap();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes[0].options, vec!["ct=application/javascript".to_string()]);
+        assert_eq!(hashes[1].algorithm, Type::SHA512);
+        assert_eq!(
+            hashes[1].digest,
+            "309ecc489c12d6eb4cc40f50c902f2b4d0ed77ee511a7c7a9bcd3ca86d4cd86f989dd35bc5ff499670da34255b45b0cfd830e81f605dcf7dc5542e93ae9cd76f"
+        );
+    }
+
+    #[test]
+    fn test_is_sri() {
+        assert!(is_sri("sha256-uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="));
+        // digest length doesn't match the algorithm
+        assert!(!is_sri("sha384-uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="));
+        // one invalid token
+        assert!(!is_sri("sha256-uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek= sha256-!!"));
+        // bare hex
+        assert!(!is_sri("sha256-b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"));
+        assert!(!is_sri(""));
+        assert!(!is_sri("   "));
+    }
+
+    #[test]
+    fn test_is_oci_digest() {
+        let sha256 = "6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b";
+        assert!(is_oci_digest(&format!("sha256:{}", sha256)));
+        assert!(is_oci_digest(&format!("blake3:{}", sha256)));
+        assert!(is_oci_digest(&format!("sha512:{}{}", sha256, sha256)));
+        // exact lengths
+        assert!(!is_oci_digest(&format!("sha256:{}0", sha256)));
+        assert!(!is_oci_digest(&format!("sha512:{}", sha256)));
+        // unregistered algorithm
+        assert!(!is_oci_digest(&format!("md5:{}", &sha256[..32])));
+        assert!(!is_oci_digest(&format!("sha256+b64u:{}", sha256)));
+        // bare hex
+        assert!(!is_oci_digest(sha256));
+    }
+}
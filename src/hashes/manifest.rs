@@ -0,0 +1,376 @@
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Component, Path};
+
+use regex::Regex;
+
+use super::{algorithm_from_name, verify_digest, verify_digest_with, Type};
+
+lazy_static! {
+    /// BSD / `--tag` style: `SHA256 (file) = hex`, openssl style: `SHA256(file)= hex`
+    static ref BSD_LINE: Regex = Regex::new(r"^([A-Za-z0-9-]+) ?\((.*)\) ?= ([0-9A-Fa-f]+)$").unwrap();
+    /// GNU coreutils style: `hex  file` (text) or `hex *file` (binary)
+    static ref GNU_LINE: Regex = Regex::new(r"^([0-9A-Fa-f]+) ([ *])(.+)$").unwrap();
+    /// SFV style: `file CRC32`
+    static ref SFV_LINE: Regex = Regex::new(r"^(.+) ([0-9A-Fa-f]{8})$").unwrap();
+}
+
+/// Checksum manifest line formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    /// GNU coreutils `*sum` output
+    Gnu,
+    /// BSD tag format (`*sum --tag`, `md5`, `openssl dgst`)
+    Bsd,
+    /// Simple File Verification (`.sfv`) CRC32 list
+    Sfv,
+}
+
+/// A single file checksum from a manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// 1 based line number in the manifest
+    pub line: usize,
+    pub format: ManifestFormat,
+    /// Algorithm named by the line, `None` for GNU lines which don't name one
+    pub algorithm: Option<Type>,
+    /// Expected digest, lowercase hex
+    pub digest: String,
+    /// Unescaped file path
+    pub path: String,
+    /// GNU binary mode marker (`*`)
+    pub binary: bool,
+}
+
+/// Why a manifest line was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestErrorKind {
+    /// The line isn't in any of the known formats
+    Malformed,
+    /// The filename has an invalid escape sequence
+    InvalidEscape,
+    /// The BSD tag names an algorithm that isn't supported
+    UnknownAlgorithm(String),
+    /// The digest doesn't have the shape of the algorithm, or of any known one
+    InvalidDigest,
+}
+
+/// A rejected manifest line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestError {
+    /// 1 based line number in the manifest
+    pub line: usize,
+    pub kind: ManifestErrorKind,
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ManifestErrorKind::Malformed => write!(f, "line {}: improperly formatted checksum line", self.line),
+            ManifestErrorKind::InvalidEscape => write!(f, "line {}: invalid escape in file name", self.line),
+            ManifestErrorKind::UnknownAlgorithm(x) => write!(f, "line {}: unknown algorithm {}", self.line, x),
+            ManifestErrorKind::InvalidDigest => write!(f, "line {}: invalid digest", self.line),
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+/// A parsed checksum manifest: the valid entries and the rejected lines
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub entries: Vec<ManifestEntry>,
+    pub errors: Vec<ManifestError>,
+}
+
+/// Outcome of checking a single manifest entry against a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyStatus {
+    /// The file matches its digest
+    Ok,
+    /// The file doesn't match its digest
+    Failed,
+    /// The path is absolute, has `..` components or is a symlink
+    /// resolving outside of the directory; the file isn't read
+    OutsideDirectory,
+    /// The file couldn't be read
+    Unreadable(String),
+}
+
+/// Result of checking a single manifest entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    /// 1 based line number in the manifest
+    pub line: usize,
+    pub path: String,
+    pub status: VerifyStatus,
+}
+
+/// Undo the coreutils file name escaping (`\\`, `\n`, `\r`)
+fn unescape(value: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            _ => return None,
+        }
+    }
+    Some(unescaped)
+}
+
+fn parse_line(number: usize, line: &str) -> Result<ManifestEntry, ManifestErrorKind> {
+    // a leading backslash marks an escaped file name
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(x) => (true, x),
+        None => (false, line),
+    };
+    let (format, algorithm, digest, path, binary) = if let Some(x) = BSD_LINE.captures(line) {
+        let algorithm = algorithm_from_name(&x[1]).ok_or_else(|| ManifestErrorKind::UnknownAlgorithm(x[1].to_string()))?;
+        (ManifestFormat::Bsd, Some(algorithm), x[3].to_string(), x[2].to_string(), false)
+    } else if let Some(x) = GNU_LINE.captures(line) {
+        (ManifestFormat::Gnu, None, x[1].to_string(), x[3].to_string(), &x[2] == "*")
+    } else if let Some(x) = SFV_LINE.captures(line) {
+        (ManifestFormat::Sfv, Some(Type::CRC32), x[2].to_string(), x[1].trim_end().to_string(), false)
+    } else {
+        return Err(ManifestErrorKind::Malformed)
+    };
+    let valid = match algorithm {
+        Some(x) => x.pattern().is_match(&digest),
+        None => Type::all().iter().any(|x| x.pattern().is_match(&digest)),
+    };
+    if !valid {
+        return Err(ManifestErrorKind::InvalidDigest)
+    }
+    let path = if escaped {
+        unescape(&path).ok_or(ManifestErrorKind::InvalidEscape)?
+    } else {
+        path
+    };
+    Ok(ManifestEntry {
+        line: number,
+        format,
+        algorithm,
+        digest: digest.to_lowercase(),
+        path,
+        binary,
+    })
+}
+
+pub fn parse_manifest(content: &str) -> Manifest {
+    //! Parse a checksum manifest: GNU coreutils `*sum` output (text and
+    //! binary markers, escaped file names), BSD tag lines
+    //! (`SHA256 (file) = hex`) and `.sfv` CRC32 lists. Formats may be mixed;
+    //! blank lines and `#` / `;` comments are skipped. Each digest is checked
+    //! against its algorithm (or any known one for GNU lines), and rejected
+    //! lines are reported with their line number.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::parse_manifest;
+    //!
+    //! fn main() {
+    //!     let manifest = parse_manifest(
+    //!         "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9 *release.tar.gz\nnot a checksum\n",
+    //!     );
+    //!     assert_eq!(manifest.entries[0].path, "release.tar.gz");
+    //!     assert_eq!(manifest.errors[0].line, 2);
+    //! }
+    //! ```
+    let mut manifest = Manifest::default();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue
+        }
+        match parse_line(i + 1, line) {
+            Ok(x) => manifest.entries.push(x),
+            Err(kind) => manifest.errors.push(ManifestError { line: i + 1, kind }),
+        }
+    }
+    manifest
+}
+
+/// A manifest path that stays within the directory it is verified against,
+/// symlinks included. A missing file is left for `File::open` to report.
+fn is_contained(directory: &Path, path: &str) -> bool {
+    if !Path::new(path).components().all(|x| matches!(x, Component::Normal(_) | Component::CurDir)) {
+        return false
+    }
+    match (directory.canonicalize(), directory.join(path).canonicalize()) {
+        (Ok(directory), Ok(file)) => file.starts_with(directory),
+        _ => true,
+    }
+}
+
+pub fn verify_manifest<P: AsRef<Path>>(manifest: &Manifest, directory: P) -> Vec<Verification> {
+    //! Verify every entry of a parsed manifest against the files in `directory`.
+    //! Entry paths are resolved relative to `directory`; absolute paths, paths
+    //! with `..` components and symlinks resolving outside of `directory` are
+    //! never opened and reported as `OutsideDirectory`.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{parse_manifest, verify_manifest, VerifyStatus};
+    //!
+    //! fn main() {
+    //!     let manifest = parse_manifest("00000000  missing.bin\n");
+    //!     let results = verify_manifest(&manifest, ".");
+    //!     assert!(matches!(results[0].status, VerifyStatus::Unreadable(_)));
+    //! }
+    //! ```
+    manifest
+        .entries
+        .iter()
+        .map(|entry| {
+            if !is_contained(directory.as_ref(), &entry.path) {
+                return Verification {
+                    line: entry.line,
+                    path: entry.path.clone(),
+                    status: VerifyStatus::OutsideDirectory,
+                }
+            }
+            let file = File::open(directory.as_ref().join(&entry.path)).map(BufReader::new);
+            let verified = file.and_then(|file| match entry.algorithm {
+                Some(x) => verify_digest_with(file, &entry.digest, x),
+                None => verify_digest(file, &entry.digest),
+            });
+            let status = match verified {
+                Ok(true) => VerifyStatus::Ok,
+                Ok(false) => VerifyStatus::Failed,
+                Err(e) => VerifyStatus::Unreadable(e.to_string()),
+            };
+            Verification {
+                line: entry.line,
+                path: entry.path.clone(),
+                status,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    #[test]
+    fn test_parse_manifest_gnu() {
+        let content = format!("{0}  hello.txt\n{0} *hello.bin\n\\{0}  dir\\\\new\\nline\n", SHA256);
+        let manifest = parse_manifest(&content);
+        assert!(manifest.errors.is_empty());
+        let paths: Vec<&str> = manifest.entries.iter().map(|x| x.path.as_str()).collect();
+        assert_eq!(paths, vec!["hello.txt", "hello.bin", "dir\\new\nline"]);
+        assert!(!manifest.entries[0].binary);
+        assert!(manifest.entries[1].binary);
+        assert_eq!(manifest.entries[0].format, ManifestFormat::Gnu);
+        assert_eq!(manifest.entries[0].algorithm, None);
+    }
+
+    #[test]
+    fn test_parse_manifest_bsd() {
+        let content = format!(
+            "SHA256 (hello world.txt) = {0}\nSHA2-256(hello.txt)= {0}\nMD5 (a) = {0}\nFOO (a) = {0}\n",
+            SHA256
+        );
+        let manifest = parse_manifest(&content);
+        assert_eq!(manifest.entries.len(), 2);
+        assert_eq!(manifest.entries[0].path, "hello world.txt");
+        assert_eq!(manifest.entries[0].algorithm, Some(Type::SHA256));
+        assert_eq!(manifest.entries[1].algorithm, Some(Type::SHA256));
+        assert_eq!(
+            manifest.errors,
+            vec![
+                ManifestError { line: 3, kind: ManifestErrorKind::InvalidDigest },
+                ManifestError { line: 4, kind: ManifestErrorKind::UnknownAlgorithm("FOO".to_string()) },
+            ]
+        );
+        assert_eq!(algorithm_from_name("SHA3-256"), Some(Type::SHA3_256));
+        assert_eq!(algorithm_from_name("BLAKE2b"), Some(Type::BLAKE2b));
+    }
+
+    #[test]
+    fn test_parse_manifest_sfv() {
+        let manifest = parse_manifest("; generated by cksfv\nhello world.txt 0D4A1185\r\n");
+        assert!(manifest.errors.is_empty());
+        assert_eq!(manifest.entries[0].path, "hello world.txt");
+        assert_eq!(manifest.entries[0].digest, "0d4a1185");
+        assert_eq!(manifest.entries[0].algorithm, Some(Type::CRC32));
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        let manifest = parse_manifest("\n# comment\nabc  file\nnonsense\n\\0d4a1185  bad\\escape\n");
+        assert!(manifest.entries.is_empty());
+        let kinds: Vec<(usize, ManifestErrorKind)> = manifest.errors.into_iter().map(|x| (x.line, x.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (3, ManifestErrorKind::InvalidDigest),
+                (4, ManifestErrorKind::Malformed),
+                (5, ManifestErrorKind::InvalidEscape),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_manifest() {
+        let directory = std::env::temp_dir().join(format!("validaten-manifest-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("hello.txt"), b"hello world").unwrap();
+        std::fs::write(directory.join("other.txt"), b"hello world!").unwrap();
+
+        let content = format!("{0}  hello.txt\n{0}  other.txt\nhello.txt 0d4a1185\n{0}  missing.txt\n", SHA256);
+        let results = verify_manifest(&parse_manifest(&content), &directory);
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(results[0].status, VerifyStatus::Ok);
+        assert_eq!(results[1].status, VerifyStatus::Failed);
+        assert_eq!(results[2].status, VerifyStatus::Ok);
+        assert!(matches!(results[3].status, VerifyStatus::Unreadable(_)));
+    }
+
+    #[test]
+    fn test_verify_manifest_outside_directory() {
+        let directory = std::env::temp_dir().join(format!("validaten-contained-{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("sub")).unwrap();
+        std::fs::write(directory.join("hello.txt"), b"hello world").unwrap();
+
+        let content = format!(
+            "{0}  ../hello.txt\n{0}  {1}\n{0}  ./sub/../hello.txt\n",
+            SHA256,
+            directory.join("hello.txt").display()
+        );
+        let results = verify_manifest(&parse_manifest(&content), directory.join("sub"));
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let statuses: Vec<VerifyStatus> = results.into_iter().map(|x| x.status).collect();
+        assert_eq!(statuses, vec![VerifyStatus::OutsideDirectory; 3]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_verify_manifest_symlink() {
+        let directory = std::env::temp_dir().join(format!("validaten-symlink-{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("sub")).unwrap();
+        std::fs::write(directory.join("hello.txt"), b"hello world").unwrap();
+        std::os::unix::fs::symlink(directory.join("hello.txt"), directory.join("sub/outside.txt")).unwrap();
+        std::os::unix::fs::symlink("inside.txt", directory.join("sub/link.txt")).unwrap();
+        std::fs::write(directory.join("sub/inside.txt"), b"hello world").unwrap();
+
+        let content = format!("{0}  outside.txt\n{0}  link.txt\n", SHA256);
+        let results = verify_manifest(&parse_manifest(&content), directory.join("sub"));
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(results[0].status, VerifyStatus::OutsideDirectory);
+        assert_eq!(results[1].status, VerifyStatus::Ok);
+    }
+}
@@ -0,0 +1,325 @@
+use super::{from_hex, to_hex, Type};
+
+/// Longest varint allowed by the multiformats spec
+const VARINT_MAX_BYTES: usize = 9;
+
+/// CIDv0 is a base58btc sha2-256 multihash: `Qm` and 44 characters
+const CID_V0_LENGTH: usize = 46;
+
+/// Multicodec code of the dag-pb codec implied by CIDv0
+const DAG_PB: u64 = 0x70;
+
+/// Multicodec code of the identity "hash", which embeds the data itself
+const IDENTITY: u64 = 0x00;
+
+/// A decoded multihash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multihash {
+    /// Multicodec code of the hash function
+    pub code: u64,
+    /// Multicodec name of the hash function, eg: `sha2-256`
+    pub name: &'static str,
+    /// Matching digest algorithm, `None` for `identity`
+    pub algorithm: Option<Type>,
+    /// Digest, lowercase hex
+    pub digest: String,
+}
+
+/// A decoded IPFS content identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cid {
+    /// CID version, 0 or 1
+    pub version: u64,
+    /// Multibase prefix of a CIDv1 (`b`, `z` or `k`)
+    pub multibase: Option<char>,
+    /// Multicodec code of the content
+    pub codec: u64,
+    /// Multicodec name of the content, if known, eg: `dag-pb`
+    pub codec_name: Option<&'static str>,
+    pub multihash: Multihash,
+}
+
+/// Hash functions of the multicodec table: name, algorithm & digest size in bytes
+fn hash_function(code: u64) -> Option<(&'static str, Option<Type>, usize)> {
+    match code {
+        0x11 => Some(("sha1", Some(Type::SHA1), 20)),
+        0x12 => Some(("sha2-256", Some(Type::SHA256), 32)),
+        0x13 => Some(("sha2-512", Some(Type::SHA512), 64)),
+        0x14 => Some(("sha3-512", Some(Type::SHA3_512), 64)),
+        0x15 => Some(("sha3-384", Some(Type::SHA3_384), 48)),
+        0x16 => Some(("sha3-256", Some(Type::SHA3_256), 32)),
+        0x17 => Some(("sha3-224", Some(Type::SHA3_224), 28)),
+        0x1b => Some(("keccak-256", Some(Type::Keccak256), 32)),
+        0x1e => Some(("blake3", Some(Type::BLAKE3), 32)),
+        0x20 => Some(("sha2-384", Some(Type::SHA384), 48)),
+        0xd5 => Some(("md5", Some(Type::MD5), 16)),
+        0x1013 => Some(("sha2-224", Some(Type::SHA224), 28)),
+        0x1053 => Some(("ripemd-160", Some(Type::RIPEMD160), 20)),
+        0x534d => Some(("sm3-256", Some(Type::SM3), 32)),
+        0xb240 => Some(("blake2b-512", Some(Type::BLAKE2b), 64)),
+        0xb260 => Some(("blake2s-256", Some(Type::BLAKE2s), 32)),
+        _ => None,
+    }
+}
+
+/// Content codecs of the multicodec table
+fn codec_name(code: u64) -> Option<&'static str> {
+    match code {
+        0x51 => Some("cbor"),
+        0x55 => Some("raw"),
+        0x70 => Some("dag-pb"),
+        0x71 => Some("dag-cbor"),
+        0x72 => Some("libp2p-key"),
+        0x78 => Some("git-raw"),
+        0x0129 => Some("dag-json"),
+        0x0200 => Some("json"),
+        0x0202 => Some("car"),
+        _ => None,
+    }
+}
+
+/// Read an unsigned LEB128 varint, rejecting overlong encodings
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(VARINT_MAX_BYTES) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            // a trailing zero byte is not the minimal encoding
+            if i > 0 && *byte == 0 {
+                return None
+            }
+            return Some((value, &bytes[i + 1..]))
+        }
+    }
+    None
+}
+
+/// RFC 4648 lowercase base32, without padding
+fn base32_decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in value.chars() {
+        let digit = match c {
+            'a'..='z' => c as u32 - 'a' as u32,
+            '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | digit;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // leftover bits are padding and must be zero
+    if bits >= 5 || buffer != 0 {
+        return None
+    }
+    Some(bytes)
+}
+
+/// Lowercase base36, leading `0`s are leading zero bytes
+fn base36_decode(value: &str) -> Option<Vec<u8>> {
+    let zeros = value.chars().take_while(|x| *x == '0').count();
+    // little endian big number
+    let mut number: Vec<u8> = vec![];
+    for c in value[zeros..].chars() {
+        let mut carry = match c {
+            '0'..='9' | 'a'..='z' => c.to_digit(36)?,
+            _ => return None,
+        };
+        for byte in number.iter_mut() {
+            carry += u32::from(*byte) * 36;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            number.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let mut bytes = vec![0u8; zeros];
+    bytes.extend(number.iter().rev());
+    Some(bytes)
+}
+
+/// Decode a multihash that must span the whole input
+fn read_multihash(bytes: &[u8]) -> Option<Multihash> {
+    let (code, rest) = read_varint(bytes)?;
+    let (length, digest) = read_varint(rest)?;
+    if digest.len() as u64 != length {
+        return None
+    }
+    let (name, algorithm) = if code == IDENTITY {
+        ("identity", None)
+    } else {
+        let (name, algorithm, size) = hash_function(code)?;
+        if digest.len() != size {
+            return None
+        }
+        (name, algorithm)
+    };
+    Some(Multihash {
+        code,
+        name,
+        algorithm,
+        digest: to_hex(digest),
+    })
+}
+
+pub fn decode_multihash(bytes: &[u8]) -> Option<Multihash> {
+    //! Decode a binary multihash: varint hash function code, varint digest
+    //! length and the digest. The digest length must match the hash function.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{decode_multihash, Type};
+    //!
+    //! fn main() {
+    //!     let mut bytes = vec![0x12, 0x20];
+    //!     bytes.extend_from_slice(&[0u8; 32]);
+    //!     let multihash = decode_multihash(&bytes).unwrap();
+    //!     assert_eq!(multihash.name, "sha2-256");
+    //!     assert_eq!(multihash.algorithm, Some(Type::SHA256));
+    //! }
+    //! ```
+    read_multihash(bytes)
+}
+
+pub fn is_multihash(value: &str) -> bool {
+    //! Check if a given hex value is a multihash with a known hash function
+    //! and a digest of the right length.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_multihash;
+    //!
+    //! fn main() {
+    //!     assert!(is_multihash("1220b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"));
+    //!     assert!(!is_multihash("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"));
+    //! }
+    //! ```
+    from_hex(value).and_then(|x| read_multihash(&x)).is_some()
+}
+
+pub fn decode_cid(value: &str) -> Option<Cid> {
+    //! Decode an IPFS content identifier: CIDv0 (`Qm...` base58btc sha2-256)
+    //! or CIDv1 with a base32 (`b`), base58btc (`z`) or base36 (`k`) multibase
+    //! prefix, reporting the codec and the embedded multihash.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::decode_cid;
+    //!
+    //! fn main() {
+    //!     let cid = decode_cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").unwrap();
+    //!     assert_eq!(cid.version, 1);
+    //!     assert_eq!(cid.codec_name, Some("dag-pb"));
+    //!     assert_eq!(cid.multihash.name, "sha2-256");
+    //! }
+    //! ```
+    if value.len() == CID_V0_LENGTH && value.starts_with("Qm") {
+        let multihash = read_multihash(&bs58::decode(value).into_vec().ok()?)?;
+        return Some(Cid {
+            version: 0,
+            multibase: None,
+            codec: DAG_PB,
+            codec_name: codec_name(DAG_PB),
+            multihash,
+        })
+    }
+    let mut chars = value.chars();
+    let multibase = chars.next()?;
+    let bytes = match multibase {
+        'b' => base32_decode(chars.as_str())?,
+        'z' => bs58::decode(chars.as_str()).into_vec().ok()?,
+        'k' => base36_decode(chars.as_str())?,
+        _ => return None,
+    };
+    let (version, rest) = read_varint(&bytes)?;
+    if version != 1 {
+        return None
+    }
+    let (codec, rest) = read_varint(rest)?;
+    Some(Cid {
+        version,
+        multibase: Some(multibase),
+        codec,
+        codec_name: codec_name(codec),
+        multihash: read_multihash(rest)?,
+    })
+}
+
+pub fn is_cid(value: &str) -> bool {
+    //! Check if a given value is a valid IPFS content identifier (CIDv0 or CIDv1).
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::is_cid;
+    //!
+    //! fn main() {
+    //!     assert!(is_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"));
+    //!     assert!(!is_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0"));
+    //! }
+    //! ```
+    decode_cid(value).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_varint() {
+        assert_eq!(read_varint(&[0x01]), Some((1, &[][..])));
+        assert_eq!(read_varint(&[0xb2, 0x40, 0xff]), Some((0x2032, &[0xff][..])));
+        // overlong & unterminated
+        assert_eq!(read_varint(&[0x81, 0x00]), None);
+        assert_eq!(read_varint(&[0x81]), None);
+    }
+
+    #[test]
+    fn test_decode_multihash() {
+        let mut sha1 = vec![0x11, 0x14];
+        sha1.extend_from_slice(&[0xaa; 20]);
+        assert_eq!(decode_multihash(&sha1).unwrap().algorithm, Some(Type::SHA1));
+        // blake2b-512 has a 2 byte varint code
+        let mut blake2b = vec![0xc0, 0xe4, 0x02, 0x40];
+        blake2b.extend_from_slice(&[0xaa; 64]);
+        assert_eq!(decode_multihash(&blake2b).unwrap().name, "blake2b-512");
+        // identity embeds data of any length
+        assert_eq!(decode_multihash(&[0x00, 0x02, 0x68, 0x69]).unwrap().digest, "6869");
+        // length mismatches
+        assert!(decode_multihash(&sha1[..21]).is_none());
+        let mut short = vec![0x12, 0x14];
+        short.extend_from_slice(&[0xaa; 20]);
+        assert!(decode_multihash(&short).is_none());
+        // unknown hash function
+        assert!(decode_multihash(&[0x7f, 0x01, 0x00]).is_none());
+    }
+
+    #[test]
+    fn test_decode_cid() {
+        let v0 = decode_cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG").unwrap();
+        assert_eq!(v0.version, 0);
+        assert_eq!(v0.codec_name, Some("dag-pb"));
+        assert_eq!(v0.multihash.algorithm, Some(Type::SHA256));
+
+        // the same content in base32 and base36
+        let base32 = decode_cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").unwrap();
+        let base36 = decode_cid("k2jmtxw8rjh1z69c6not3wtdxb0u3urbzhyll1t9jg6ox26dhi5sfi1m").unwrap();
+        assert_eq!(base32.multihash, base36.multihash);
+        assert_eq!(base32.multibase, Some('b'));
+        assert_eq!(base36.multibase, Some('k'));
+
+        // raw codec in base58btc
+        let raw = decode_cid("zb2rhe5P4gXftAwvA4eXQ5HJwsER2owDyS9sKaQRRVQPn93bA").unwrap();
+        assert_eq!(raw.codec_name, Some("raw"));
+
+        // unknown multibase & bad checksum character
+        assert!(decode_cid("xafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").is_none());
+        assert!(decode_cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzd1").is_none());
+        assert!(decode_cid("").is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use regex::Regex;
+
+use super::{algorithm_from_name, from_hex, Type};
+
+lazy_static! {
+    /// `algorithm:` or `algorithm=` prefix, eg: `sha256:`, `MD5=`
+    static ref ALGORITHM_PREFIX: Regex = Regex::new(r"^([A-Za-z][A-Za-z0-9_-]*)\s*[:=]\s*").unwrap();
+}
+
+/// Separators accepted between hex digits, besides whitespace
+const SEPARATORS: [char; 2] = [':', '-'];
+
+/// A hash value reduced to its canonical form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedHash {
+    /// Canonical lowercase hex
+    pub value: String,
+    /// Algorithm named by the prefix, eg: `sha256:`
+    pub algorithm: Option<Type>,
+    /// Stripped prefix as it appeared, eg: `0x`, `sha256:`
+    pub prefix: Option<String>,
+    /// Separator stripped from between the digits, `' '` for whitespace
+    pub separator: Option<char>,
+}
+
+pub fn normalize_hash(value: &str) -> Option<NormalizedHash> {
+    //! Normalize a pasted hash value to canonical lowercase hex: strip
+    //! surrounding whitespace, an algorithm prefix (`sha256:`, `MD5=`), a `0x`
+    //! prefix and `:` / `-` / whitespace separators between the digits.
+    //! The algorithm named by the prefix is reported as a hint.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::hashes::{normalize_hash, Type};
+    //!
+    //! fn main() {
+    //!     let normalized = normalize_hash(" SHA1:2A:AE:6C:35:C9:4F:CF:B4:15:DB:E9:5F:40:8B:9C:E9:1E:E8:46:ED ").unwrap();
+    //!     assert_eq!(normalized.value, "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed");
+    //!     assert_eq!(normalized.algorithm, Some(Type::SHA1));
+    //!     assert_eq!(normalized.prefix, Some("SHA1:".to_string()));
+    //!     assert_eq!(normalized.separator, Some(':'));
+    //! }
+    //! ```
+    let trimmed = value.trim();
+    let mut rest = trimmed;
+    let mut algorithm = None;
+    if let Some(x) = ALGORITHM_PREFIX.captures(rest) {
+        // `ab:cd:...` is separated hex, not a prefix
+        if let Some(hash) = algorithm_from_name(&x[1]) {
+            algorithm = Some(hash);
+            rest = &rest[x[0].len()..];
+        }
+    }
+    if rest.starts_with("0x") || rest.starts_with("0X") {
+        rest = &rest[2..];
+    }
+    let prefix = trimmed[..trimmed.len() - rest.len()].trim_end();
+    let prefix = if prefix.is_empty() { None } else { Some(prefix.to_string()) };
+
+    let mut separators = rest.chars().filter(|x| SEPARATORS.contains(x));
+    let separator = match separators.next() {
+        // a single kind of separator throughout
+        Some(x) if separators.any(|y| y != x) => return None,
+        Some(x) => Some(x),
+        None if rest.contains(char::is_whitespace) => Some(' '),
+        None => None,
+    };
+    let digits: String = rest
+        .chars()
+        .filter(|x| !SEPARATORS.contains(x) && !x.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    if digits.is_empty() || from_hex(&digits).is_none() {
+        return None
+    }
+    Some(NormalizedHash {
+        value: digits,
+        algorithm,
+        prefix,
+        separator,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_hash() {
+        let prefixed = normalize_hash("0xB94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9").unwrap();
+        assert_eq!(prefixed.value, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+        assert_eq!(prefixed.prefix, Some("0x".to_string()));
+        assert_eq!(prefixed.algorithm, None);
+
+        let algorithm = normalize_hash("sha3-256 = 644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938").unwrap();
+        assert_eq!(algorithm.algorithm, Some(Type::SHA3_256));
+        assert_eq!(algorithm.prefix, Some("sha3-256 =".to_string()));
+
+        let wrapped = normalize_hash("5eb63bbb e01eeed0\n93cb22bb 8f5acdc3\n").unwrap();
+        assert_eq!(wrapped.value, "5eb63bbbe01eeed093cb22bb8f5acdc3");
+        assert_eq!(wrapped.separator, Some(' '));
+
+        let dashed = normalize_hash("5e-b6-3b-bb-e0-1e-ee-d0-93-cb-22-bb-8f-5a-cd-c3").unwrap();
+        assert_eq!(dashed.separator, Some('-'));
+        assert_eq!(dashed.prefix, None);
+
+        assert_eq!(normalize_hash("cbf43926").unwrap().separator, None);
+        // mixed separators, unknown prefix, odd length, empty
+        assert!(normalize_hash("5e:b6-3b").is_none());
+        assert!(normalize_hash("foo:5eb63bbb").is_none());
+        assert!(normalize_hash("5eb").is_none());
+        assert!(normalize_hash("sha256:").is_none());
+    }
+}
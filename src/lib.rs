@@ -1,7 +1,7 @@
 #[cfg(any(
 feature = "crypto",
 feature = "hashes",
-feature = "creditcard",
+feature = "passwords",
 feature = "internet"
 ))]
 #[macro_use]
@@ -13,6 +13,12 @@ pub mod crypto;
 #[cfg(feature = "hashes")]
 pub mod hashes;
 
+#[cfg(feature = "passwords")]
+pub mod passwords;
+
+#[cfg(feature = "jwt")]
+pub mod jwt;
+
 #[cfg(feature = "creditcard")]
 pub mod creditcard;
 
@@ -0,0 +1,495 @@
+use regex::{Captures, Regex};
+
+lazy_static! {
+    // Modular crypt formats
+    static ref MD5CRYPT: Regex = Regex::new(r"^\$1\$(?P<salt>[./0-9A-Za-z]{0,8})\$[./0-9A-Za-z]{22}$").unwrap();
+    static ref CISCO_TYPE5: Regex = Regex::new(r"^\$1\$(?P<salt>[./0-9A-Za-z]{4})\$[./0-9A-Za-z]{22}$").unwrap();
+    static ref APR1: Regex = Regex::new(r"^\$apr1\$(?P<salt>[./0-9A-Za-z]{0,8})\$[./0-9A-Za-z]{22}$").unwrap();
+    static ref BCRYPT: Regex = Regex::new(r"^\$2[abxy]?\$(?P<cost>0[4-9]|[12][0-9]|3[01])\$(?P<salt>[./0-9A-Za-z]{22})[./0-9A-Za-z]{31}$").unwrap();
+    static ref SHA256CRYPT: Regex = Regex::new(r"^\$5\$(rounds=(?P<rounds>[0-9]+)\$)?(?P<salt>[./0-9A-Za-z]{0,16})\$[./0-9A-Za-z]{43}$").unwrap();
+    static ref SHA512CRYPT: Regex = Regex::new(r"^\$6\$(rounds=(?P<rounds>[0-9]+)\$)?(?P<salt>[./0-9A-Za-z]{0,16})\$[./0-9A-Za-z]{86}$").unwrap();
+    static ref SHA1CRYPT: Regex = Regex::new(r"^\$sha1\$(?P<rounds>[0-9]+)\$(?P<salt>[./0-9A-Za-z]{0,64})\$[./0-9A-Za-z]{28}$").unwrap();
+    static ref YESCRYPT: Regex = Regex::new(r"^\$y\$[./0-9A-Za-z]+\$(?P<salt>[./0-9A-Za-z]*)\$[./0-9A-Za-z]{43}$").unwrap();
+    static ref SCRYPT_CRYPT: Regex = Regex::new(r"^\$7\$[./0-9A-Za-z]{11}(?P<salt>[^$]*)\$[./0-9A-Za-z]{43}$").unwrap();
+    static ref PHPASS: Regex = Regex::new(r"^\$[PH]\$[./0-9A-Za-z](?P<salt>[./0-9A-Za-z]{8})[./0-9A-Za-z]{22}$").unwrap();
+    static ref DRUPAL7: Regex = Regex::new(r"^\$S\$[./0-9A-Za-z](?P<salt>[./0-9A-Za-z]{8})[./0-9A-Za-z]{43}$").unwrap();
+
+    // PHC string format
+    static ref ARGON2I: Regex = Regex::new(r"^\$argon2i\$(v=[0-9]+\$)?m=(?P<memory>[0-9]+),t=(?P<rounds>[0-9]+),p=(?P<parallelism>[0-9]+)\$(?P<salt>[A-Za-z0-9+/]+)\$[A-Za-z0-9+/]+$").unwrap();
+    static ref ARGON2D: Regex = Regex::new(r"^\$argon2d\$(v=[0-9]+\$)?m=(?P<memory>[0-9]+),t=(?P<rounds>[0-9]+),p=(?P<parallelism>[0-9]+)\$(?P<salt>[A-Za-z0-9+/]+)\$[A-Za-z0-9+/]+$").unwrap();
+    static ref ARGON2ID: Regex = Regex::new(r"^\$argon2id\$(v=[0-9]+\$)?m=(?P<memory>[0-9]+),t=(?P<rounds>[0-9]+),p=(?P<parallelism>[0-9]+)\$(?P<salt>[A-Za-z0-9+/]+)\$[A-Za-z0-9+/]+$").unwrap();
+    static ref SCRYPT_PHC: Regex = Regex::new(r"^\$scrypt\$ln=(?P<cost>[0-9]+),r=[0-9]+,p=(?P<parallelism>[0-9]+)\$(?P<salt>[A-Za-z0-9+/.]+)\$[A-Za-z0-9+/.]+$").unwrap();
+    static ref SCRYPT_HASHCAT: Regex = Regex::new(r"^SCRYPT:(?P<n>[0-9]+):[0-9]+:(?P<parallelism>[0-9]+):(?P<salt>[A-Za-z0-9+/=]+):[A-Za-z0-9+/=]+$").unwrap();
+    static ref PASSLIB_PBKDF2_SHA1: Regex = Regex::new(r"^\$pbkdf2\$(?P<rounds>[0-9]+)\$(?P<salt>[./0-9A-Za-z]+)\$[./0-9A-Za-z]{27}$").unwrap();
+    static ref PASSLIB_PBKDF2_SHA256: Regex = Regex::new(r"^\$pbkdf2-sha256\$(?P<rounds>[0-9]+)\$(?P<salt>[./0-9A-Za-z]+)\$[./0-9A-Za-z]{43}$").unwrap();
+    static ref PASSLIB_PBKDF2_SHA512: Regex = Regex::new(r"^\$pbkdf2-sha512\$(?P<rounds>[0-9]+)\$(?P<salt>[./0-9A-Za-z]+)\$[./0-9A-Za-z]{86}$").unwrap();
+
+    // Framework formats
+    static ref DJANGO_PBKDF2_SHA256: Regex = Regex::new(r"^pbkdf2_sha256\$(?P<rounds>[0-9]+)\$(?P<salt>[^$]+)\$[A-Za-z0-9+/]{43}=$").unwrap();
+    static ref DJANGO_PBKDF2_SHA1: Regex = Regex::new(r"^pbkdf2_sha1\$(?P<rounds>[0-9]+)\$(?P<salt>[^$]+)\$[A-Za-z0-9+/]{27}=$").unwrap();
+    static ref WERKZEUG_PBKDF2: Regex = Regex::new(r"^pbkdf2:(sha1|sha256|sha512)(:(?P<rounds>[0-9]+))?\$(?P<salt>[^$]+)\$[0-9a-f]+$").unwrap();
+    static ref WERKZEUG_SCRYPT: Regex = Regex::new(r"^scrypt:(?P<n>[0-9]+):[0-9]+:(?P<parallelism>[0-9]+)\$(?P<salt>[^$]+)\$[0-9a-f]+$").unwrap();
+    static ref POSTGRES_SCRAM: Regex = Regex::new(r"^SCRAM-SHA-256\$(?P<rounds>[0-9]+):(?P<salt>[A-Za-z0-9+/=]+)\$[A-Za-z0-9+/=]+:[A-Za-z0-9+/=]+$").unwrap();
+
+    // LDAP
+    static ref LDAP_SHA: Regex = Regex::new(r"^\{SHA\}[A-Za-z0-9+/]{27}=$").unwrap();
+    static ref LDAP_SSHA: Regex = Regex::new(r"^\{SSHA\}[A-Za-z0-9+/]{28,}={0,2}$").unwrap();
+    static ref LDAP_SSHA256: Regex = Regex::new(r"^\{SSHA256\}[A-Za-z0-9+/]{44,}={0,2}$").unwrap();
+    static ref LDAP_SSHA512: Regex = Regex::new(r"^\{SSHA512\}[A-Za-z0-9+/]{87,}={0,2}$").unwrap();
+    static ref LDAP_MD5: Regex = Regex::new(r"^\{MD5\}[A-Za-z0-9+/]{22}==$").unwrap();
+
+    // Databases, Windows & Network devices
+    static ref MYSQL41: Regex = Regex::new(r"^\*[0-9A-F]{40}$").unwrap();
+    /// NTLM, raw MD5 & LM share the bare 32 hex form
+    static ref HEX32: Regex = Regex::new(r"^[0-9A-Fa-f]{32}$").unwrap();
+    static ref PWDUMP: Regex = Regex::new(r"^[^:]+:[0-9]+:[0-9A-Fa-f]{32}:[0-9A-Fa-f]{32}:::$").unwrap();
+    /// Salt offset and at least 4 encrypted characters
+    static ref CISCO_TYPE7: Regex = Regex::new(r"^(0[0-9]|1[0-5])([0-9A-F]{2}){4,}$").unwrap();
+    static ref CISCO_TYPE8: Regex = Regex::new(r"^\$8\$(?P<salt>[./0-9A-Za-z]{14})\$[./0-9A-Za-z]{43}$").unwrap();
+    static ref CISCO_TYPE9: Regex = Regex::new(r"^\$9\$(?P<salt>[./0-9A-Za-z]{14})\$[./0-9A-Za-z]{43}$").unwrap();
+}
+
+/// A password hash format recognized in a given value,
+/// along with the parameters that could be parsed from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHash {
+    /// Format name, eg: `bcrypt`, `Argon2id`
+    pub name: &'static str,
+    /// hashcat hash mode (`-m`)
+    pub hashcat_mode: Option<u32>,
+    /// John the Ripper format (`--format`)
+    pub john_format: Option<&'static str>,
+    /// Work factor as a power of two: bcrypt cost, scrypt log2(N)
+    pub cost: Option<u32>,
+    /// Iterations: crypt rounds, PBKDF2 iterations, Argon2 time cost
+    pub rounds: Option<u32>,
+    /// Argon2 memory in KiB
+    pub memory: Option<u32>,
+    /// Argon2 lanes / scrypt parallelism
+    pub parallelism: Option<u32>,
+    /// Salt as it appears in the hash
+    pub salt: Option<String>,
+}
+
+enum Type {
+    BCrypt,
+    MD5Crypt,
+    CiscoType5,
+    APR1,
+    SHA256Crypt,
+    SHA512Crypt,
+    SHA1Crypt,
+    YesCrypt,
+    SCryptCrypt,
+    PHPass,
+    Drupal7,
+    Argon2i,
+    Argon2d,
+    Argon2id,
+    SCryptPHC,
+    SCryptHashcat,
+    PasslibPBKDF2SHA1,
+    PasslibPBKDF2SHA256,
+    PasslibPBKDF2SHA512,
+    DjangoPBKDF2SHA256,
+    DjangoPBKDF2SHA1,
+    WerkzeugPBKDF2,
+    WerkzeugSCrypt,
+    PostgresSCRAM,
+    LdapSHA,
+    LdapSSHA,
+    LdapSSHA256,
+    LdapSSHA512,
+    LdapMD5,
+    MySQL41,
+    CiscoType8,
+    CiscoType9,
+    PwDump,
+    Ntlm,
+    RawMD5,
+    Lm,
+    CiscoType7,
+}
+
+impl Type {
+    fn name(&self) -> &'static str {
+        match *self {
+            Type::BCrypt => "bcrypt",
+            Type::MD5Crypt => "md5crypt",
+            Type::CiscoType5 => "Cisco Type 5",
+            Type::APR1 => "Apache apr1",
+            Type::SHA256Crypt => "sha256crypt",
+            Type::SHA512Crypt => "sha512crypt",
+            Type::SHA1Crypt => "sha1crypt",
+            Type::YesCrypt => "yescrypt",
+            Type::SCryptCrypt => "scrypt (crypt)",
+            Type::PHPass => "phpass",
+            Type::Drupal7 => "Drupal 7",
+            Type::Argon2i => "Argon2i",
+            Type::Argon2d => "Argon2d",
+            Type::Argon2id => "Argon2id",
+            Type::SCryptPHC => "scrypt",
+            Type::SCryptHashcat => "scrypt",
+            Type::PasslibPBKDF2SHA1 => "Passlib PBKDF2-SHA1",
+            Type::PasslibPBKDF2SHA256 => "Passlib PBKDF2-SHA256",
+            Type::PasslibPBKDF2SHA512 => "Passlib PBKDF2-SHA512",
+            Type::DjangoPBKDF2SHA256 => "Django PBKDF2-SHA256",
+            Type::DjangoPBKDF2SHA1 => "Django PBKDF2-SHA1",
+            Type::WerkzeugPBKDF2 => "Werkzeug PBKDF2",
+            Type::WerkzeugSCrypt => "Werkzeug scrypt",
+            Type::PostgresSCRAM => "PostgreSQL SCRAM-SHA-256",
+            Type::LdapSHA => "LDAP SHA",
+            Type::LdapSSHA => "LDAP SSHA",
+            Type::LdapSSHA256 => "LDAP SSHA256",
+            Type::LdapSSHA512 => "LDAP SSHA512",
+            Type::LdapMD5 => "LDAP MD5",
+            Type::MySQL41 => "MySQL 4.1+",
+            Type::CiscoType8 => "Cisco Type 8",
+            Type::CiscoType9 => "Cisco Type 9",
+            Type::PwDump => "NTLM (pwdump)",
+            Type::Ntlm => "NTLM",
+            Type::RawMD5 => "MD5",
+            Type::Lm => "LM",
+            Type::CiscoType7 => "Cisco Type 7",
+        }
+    }
+
+    fn pattern<'a>(&self) -> &'a Regex {
+        match *self {
+            Type::BCrypt => &BCRYPT,
+            Type::MD5Crypt => &MD5CRYPT,
+            Type::CiscoType5 => &CISCO_TYPE5,
+            Type::APR1 => &APR1,
+            Type::SHA256Crypt => &SHA256CRYPT,
+            Type::SHA512Crypt => &SHA512CRYPT,
+            Type::SHA1Crypt => &SHA1CRYPT,
+            Type::YesCrypt => &YESCRYPT,
+            Type::SCryptCrypt => &SCRYPT_CRYPT,
+            Type::PHPass => &PHPASS,
+            Type::Drupal7 => &DRUPAL7,
+            Type::Argon2i => &ARGON2I,
+            Type::Argon2d => &ARGON2D,
+            Type::Argon2id => &ARGON2ID,
+            Type::SCryptPHC => &SCRYPT_PHC,
+            Type::SCryptHashcat => &SCRYPT_HASHCAT,
+            Type::PasslibPBKDF2SHA1 => &PASSLIB_PBKDF2_SHA1,
+            Type::PasslibPBKDF2SHA256 => &PASSLIB_PBKDF2_SHA256,
+            Type::PasslibPBKDF2SHA512 => &PASSLIB_PBKDF2_SHA512,
+            Type::DjangoPBKDF2SHA256 => &DJANGO_PBKDF2_SHA256,
+            Type::DjangoPBKDF2SHA1 => &DJANGO_PBKDF2_SHA1,
+            Type::WerkzeugPBKDF2 => &WERKZEUG_PBKDF2,
+            Type::WerkzeugSCrypt => &WERKZEUG_SCRYPT,
+            Type::PostgresSCRAM => &POSTGRES_SCRAM,
+            Type::LdapSHA => &LDAP_SHA,
+            Type::LdapSSHA => &LDAP_SSHA,
+            Type::LdapSSHA256 => &LDAP_SSHA256,
+            Type::LdapSSHA512 => &LDAP_SSHA512,
+            Type::LdapMD5 => &LDAP_MD5,
+            Type::MySQL41 => &MYSQL41,
+            Type::CiscoType8 => &CISCO_TYPE8,
+            Type::CiscoType9 => &CISCO_TYPE9,
+            Type::PwDump => &PWDUMP,
+            Type::Ntlm | Type::RawMD5 | Type::Lm => &HEX32,
+            Type::CiscoType7 => &CISCO_TYPE7,
+        }
+    }
+
+    fn hashcat_mode(&self) -> Option<u32> {
+        match *self {
+            Type::BCrypt => Some(3200),
+            Type::MD5Crypt | Type::CiscoType5 => Some(500),
+            Type::APR1 => Some(1600),
+            Type::SHA256Crypt => Some(7400),
+            Type::SHA512Crypt => Some(1800),
+            Type::SHA1Crypt => Some(15100),
+            Type::PHPass => Some(400),
+            Type::Drupal7 => Some(7900),
+            Type::Argon2i | Type::Argon2d | Type::Argon2id => Some(34000),
+            Type::SCryptHashcat => Some(8900),
+            Type::PasslibPBKDF2SHA1 => Some(20400),
+            Type::PasslibPBKDF2SHA256 => Some(20300),
+            Type::PasslibPBKDF2SHA512 => Some(20200),
+            Type::DjangoPBKDF2SHA256 => Some(10000),
+            Type::PostgresSCRAM => Some(28600),
+            Type::LdapSHA => Some(101),
+            Type::LdapSSHA => Some(111),
+            Type::LdapSSHA256 => Some(1411),
+            Type::LdapSSHA512 => Some(1711),
+            Type::MySQL41 => Some(300),
+            Type::CiscoType8 => Some(9200),
+            Type::CiscoType9 => Some(9300),
+            Type::PwDump | Type::Ntlm => Some(1000),
+            Type::RawMD5 => Some(0),
+            Type::Lm => Some(3000),
+            _ => None,
+        }
+    }
+
+    fn john_format(&self) -> Option<&'static str> {
+        match *self {
+            Type::BCrypt => Some("bcrypt"),
+            Type::MD5Crypt | Type::CiscoType5 | Type::APR1 => Some("md5crypt"),
+            Type::SHA256Crypt => Some("sha256crypt"),
+            Type::SHA512Crypt => Some("sha512crypt"),
+            Type::SHA1Crypt => Some("sha1crypt"),
+            Type::YesCrypt | Type::SCryptCrypt => Some("crypt"),
+            Type::PHPass => Some("phpass"),
+            Type::Drupal7 => Some("drupal7"),
+            Type::Argon2i | Type::Argon2d | Type::Argon2id => Some("argon2"),
+            Type::SCryptPHC | Type::SCryptHashcat => Some("scrypt"),
+            Type::PasslibPBKDF2SHA1 => Some("pbkdf2-hmac-sha1"),
+            Type::PasslibPBKDF2SHA256 | Type::CiscoType8 => Some("pbkdf2-hmac-sha256"),
+            Type::PasslibPBKDF2SHA512 => Some("pbkdf2-hmac-sha512"),
+            Type::DjangoPBKDF2SHA256 => Some("django"),
+            Type::LdapSHA => Some("nsldap"),
+            Type::LdapSSHA => Some("salted-sha1"),
+            Type::LdapSSHA512 => Some("ssha512"),
+            Type::MySQL41 => Some("mysql-sha1"),
+            Type::PwDump | Type::Ntlm => Some("nt"),
+            Type::RawMD5 => Some("raw-md5"),
+            Type::Lm => Some("lm"),
+            _ => None,
+        }
+    }
+
+    /// Rounds implied by the format when the hash doesn't spell them out
+    fn default_rounds(&self) -> Option<u32> {
+        match *self {
+            Type::SHA256Crypt | Type::SHA512Crypt => Some(5000),
+            _ => None,
+        }
+    }
+
+    fn all() -> Vec<Type> {
+        vec![
+            Type::BCrypt,
+            Type::MD5Crypt,
+            Type::CiscoType5,
+            Type::APR1,
+            Type::SHA256Crypt,
+            Type::SHA512Crypt,
+            Type::SHA1Crypt,
+            Type::YesCrypt,
+            Type::SCryptCrypt,
+            Type::PHPass,
+            Type::Drupal7,
+            Type::Argon2i,
+            Type::Argon2d,
+            Type::Argon2id,
+            Type::SCryptPHC,
+            Type::SCryptHashcat,
+            Type::PasslibPBKDF2SHA1,
+            Type::PasslibPBKDF2SHA256,
+            Type::PasslibPBKDF2SHA512,
+            Type::DjangoPBKDF2SHA256,
+            Type::DjangoPBKDF2SHA1,
+            Type::WerkzeugPBKDF2,
+            Type::WerkzeugSCrypt,
+            Type::PostgresSCRAM,
+            Type::LdapSHA,
+            Type::LdapSSHA,
+            Type::LdapSSHA256,
+            Type::LdapSSHA512,
+            Type::LdapMD5,
+            Type::MySQL41,
+            Type::CiscoType8,
+            Type::CiscoType9,
+            Type::PwDump,
+            Type::Ntlm,
+            Type::RawMD5,
+            Type::Lm,
+            Type::CiscoType7,
+        ]
+    }
+
+    /// Checks the pattern alone cannot express
+    fn is_plausible(&self, value: &str) -> bool {
+        match self {
+            // a Type 7 secret of digits only is more likely a number or a PIN
+            Type::CiscoType7 => value.contains(|x: char| ('A'..='F').contains(&x)),
+            _ => true,
+        }
+    }
+
+    /// Build the result from the named groups of the matched pattern
+    fn parse(&self, captures: &Captures) -> PasswordHash {
+        let number = |group: &str| captures.name(group).and_then(|x| x.as_str().parse::<u32>().ok());
+        // scrypt N is a power of two, report it as log2(N) like `ln=`
+        let cost = number("cost").or_else(|| {
+            number("n").filter(|n| n.is_power_of_two()).map(|n| n.trailing_zeros())
+        });
+        PasswordHash {
+            name: self.name(),
+            hashcat_mode: self.hashcat_mode(),
+            john_format: self.john_format(),
+            cost,
+            rounds: number("rounds").or_else(|| self.default_rounds()),
+            memory: number("memory"),
+            parallelism: number("parallelism"),
+            salt: captures.name("salt").map(|x| x.as_str().to_string()),
+        }
+    }
+}
+
+pub fn identify_password_hash(value: &str) -> Vec<PasswordHash> {
+    //! Identify the password hash formats a given value could be,
+    //! most likely first, along with their parsed parameters and
+    //! hashcat / John the Ripper modes. Ambiguous values yield every
+    //! candidate, eg: bare 32 hex is reported as NTLM, MD5 and LM.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::passwords::identify_password_hash;
+    //!
+    //! fn main() {
+    //!     let candidates = identify_password_hash("$2b$12$GhvMmNVjRW29ulnudl.LbuAnUtN/LRfe1JsBm1Xu6LE3059z5Tr8m");
+    //!     assert_eq!(candidates[0].name, "bcrypt");
+    //!     assert_eq!(candidates[0].cost, Some(12));
+    //!     assert_eq!(candidates[0].hashcat_mode, Some(3200));
+    //! }
+    //! ```
+    let value = value.trim();
+    Type::all()
+        .iter()
+        .filter(|hash| hash.is_plausible(value))
+        .filter_map(|hash| hash.pattern().captures(value).map(|x| hash.parse(&x)))
+        .collect()
+}
+
+pub fn is_password_hash(value: &str) -> bool {
+    //! Check if a given value corresponds to a known password hash format.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::passwords::is_password_hash;
+    //!
+    //! fn main() {
+    //!     assert!(is_password_hash("*2470C0C06DEE42FD1618BB99005ADCA2EC9D1E19"));
+    //!     assert!(!is_password_hash("password"));
+    //! }
+    //! ```
+    !identify_password_hash(value).is_empty()
+}
+
+pub fn which_password_hash(value: &str) -> Option<&str> {
+    //! Check if a given value corresponds to a password hash format
+    //! and outputs the most likely format name.
+    //!
+    //! ## Example Usage
+    //! ```rust
+    //! use validaten::passwords::which_password_hash;
+    //!
+    //! fn main() {
+    //!     assert_eq!(which_password_hash("{SSHA}DkMTwBl+a/3DQTxCYEApdUtNXGgdUac3"), Some("LDAP SSHA"));
+    //! }
+    //! ```
+    identify_password_hash(value).first().map(|x| x.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first(value: &str) -> PasswordHash {
+        identify_password_hash(value).remove(0)
+    }
+
+    #[test]
+    fn test_which_password_hash() {
+        // Modular crypt
+        assert_eq!(which_password_hash("$1$28772684$iEwNOgGugqO9.bIz5sk8k/"), Some("md5crypt"));
+        assert_eq!(which_password_hash("$apr1$71850310$gh9m4xcAn3MGxogwX/ztb."), Some("Apache apr1"));
+        assert_eq!(which_password_hash("$2a$05$LhayLxezLhK1LhWvKxCyLOj0j1u.Kj0jZ0pEmm134uzrQlFvQJLF6"), Some("bcrypt"));
+        assert_eq!(which_password_hash("$5$rounds=5000$GX7BopJZJxPc/KEK$le16UF8I2Anb.rOrn22AUPWvzUETDGefUmAV8AZkGcD"), Some("sha256crypt"));
+        assert_eq!(
+            which_password_hash("$6$52450745$k5ka2p8bFuSmoVT1tzOyyuaREkkKBcCNqoDKzYiJL9RaE8yMnPgh2XzzF0NDrUhgrcLwg78xs1w5pJiypEdFX/"),
+            Some("sha512crypt")
+        );
+        assert_eq!(which_password_hash("$y$j9T$F5Jx5fExrKuPp53xLKQ..1$X3DX6M94c7o.9agCG9G317fhZg9SqC.5i5rd.RhAtQ7"), Some("yescrypt"));
+        assert_eq!(which_password_hash("$P$984478476IagS59wHZvyQMArzfx58u."), Some("phpass"));
+        // PHC
+        assert_eq!(
+            which_password_hash("$argon2id$v=19$m=65536,t=3,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG"),
+            Some("Argon2id")
+        );
+        assert_eq!(which_password_hash("$scrypt$ln=16,r=8,p=1$aM15713r3Xsvxbi31lqr1Q$nFNh2CVHVjNldFVKDHDlm4CbdRSCdEBsjjJxD+iCs5E"), Some("scrypt"));
+        // Frameworks
+        assert_eq!(
+            which_password_hash("pbkdf2_sha256$20000$H0dPx8NeajVu$GiC4k5kqbbR9qWBlsRgDywNqC2vd9kqfk7zdorEnNas="),
+            Some("Django PBKDF2-SHA256")
+        );
+        assert_eq!(
+            which_password_hash("pbkdf2:sha256:600000$bIy4mLJb$8d2ef6b6e4a7e5b9a1c0f1e2d3c4b5a69788796a5b4c3d2e1f0a1b2c3d4e5f60"),
+            Some("Werkzeug PBKDF2")
+        );
+        // LDAP
+        assert_eq!(which_password_hash("{SHA}qUqP5cyxm6YcTAhz05Hph5gvu9M="), Some("LDAP SHA"));
+        // Databases, Windows & Network devices
+        assert_eq!(which_password_hash("*2470C0C06DEE42FD1618BB99005ADCA2EC9D1E19"), Some("MySQL 4.1+"));
+        assert_eq!(which_password_hash("b4b9b02e6f09a9bd760f388b67351e2b"), Some("NTLM"));
+        assert_eq!(
+            which_password_hash("Administrator:500:aad3b435b51404eeaad3b435b51404ee:31d6cfe0d16ae931b73c59d7e0c089c0:::"),
+            Some("NTLM (pwdump)")
+        );
+        assert_eq!(which_password_hash("$8$TnGX/fE4KGHOVU$pEhnEvxrvaynpi8j4f.EMHr6M.FzU8xnZnBr/tJdFWk"), Some("Cisco Type 8"));
+        assert_eq!(which_password_hash("$9$2MJBozw/9R3UsU$2lFhcKvpghcyw8deP25GOfyZaagyUOGBymkryvOdfo6"), Some("Cisco Type 9"));
+        assert_eq!(which_password_hash("094F471A1A0A"), Some("Cisco Type 7"));
+        // Unknown
+        assert_eq!(which_password_hash("hunter2"), None);
+        assert_eq!(which_password_hash("$2b$99$LhayLxezLhK1LhWvKxCyLOj0j1u.Kj0jZ0pEmm134uzrQlFvQJLF6"), None);
+    }
+
+    #[test]
+    fn test_identify_password_hash() {
+        let bcrypt = first("$2b$12$GhvMmNVjRW29ulnudl.LbuAnUtN/LRfe1JsBm1Xu6LE3059z5Tr8m");
+        assert_eq!(bcrypt.cost, Some(12));
+        assert_eq!(bcrypt.salt, Some("GhvMmNVjRW29ulnudl.Lbu".to_string()));
+        assert_eq!(bcrypt.john_format, Some("bcrypt"));
+
+        let sha512crypt = first("$6$52450745$k5ka2p8bFuSmoVT1tzOyyuaREkkKBcCNqoDKzYiJL9RaE8yMnPgh2XzzF0NDrUhgrcLwg78xs1w5pJiypEdFX/");
+        assert_eq!(sha512crypt.rounds, Some(5000));
+        assert_eq!(sha512crypt.salt, Some("52450745".to_string()));
+        assert_eq!(sha512crypt.hashcat_mode, Some(1800));
+
+        let argon2 = first("$argon2id$v=19$m=65536,t=3,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG");
+        assert_eq!(argon2.memory, Some(65536));
+        assert_eq!(argon2.rounds, Some(3));
+        assert_eq!(argon2.parallelism, Some(4));
+        assert_eq!(argon2.salt, Some("c29tZXNhbHQ".to_string()));
+
+        let scrypt = first("SCRYPT:1024:1:1:MDIwMzMwNTQwNDQyNQ==:5FW+zWivLxgCWj7qLiQbeC8zaNQ+qdO0NUinvqyFcfo=");
+        assert_eq!(scrypt.cost, Some(10));
+        assert_eq!(scrypt.hashcat_mode, Some(8900));
+
+        let django = first("pbkdf2_sha256$20000$H0dPx8NeajVu$GiC4k5kqbbR9qWBlsRgDywNqC2vd9kqfk7zdorEnNas=");
+        assert_eq!(django.rounds, Some(20000));
+        assert_eq!(django.hashcat_mode, Some(10000));
+
+        // a 4 character md5crypt salt may also be a Cisco type 5 secret
+        let names: Vec<&str> = identify_password_hash("$1$pdQG$o8nrSzsGXeaduXrjlvKc91")
+            .iter()
+            .map(|x| x.name)
+            .collect();
+        assert_eq!(names, vec!["md5crypt", "Cisco Type 5"]);
+
+        // bare 32 hex can't be told apart, NTLM is the most likely password hash
+        let candidates = identify_password_hash("b4b9b02e6f09a9bd760f388b67351e2b");
+        let names: Vec<&str> = candidates.iter().map(|x| x.name).collect();
+        assert_eq!(names, vec!["NTLM", "MD5", "LM"]);
+        assert_eq!(candidates[1].hashcat_mode, Some(0));
+        assert_eq!(candidates[2].john_format, Some("lm"));
+    }
+
+    #[test]
+    fn test_is_password_hash() {
+        assert!(is_password_hash("{SSHA512}SCMmLlStPIxVtJc8Y6REiGTMsgSEFF7xVQFoYZYg39H0nEeDuK/fWxxNZCdSYlRgJK3U3q0lYTka3Nre2CjXzeNUjbvHabYP"));
+        assert!(is_password_hash("SCRAM-SHA-256$4096:fSHXL4PdiJ5dSh2nKk/JqA==$Uq8/mZKdyKn5dcJWmxnzcbWPV3fBGMUIkNGrp5tSYTQ=:XRjoLyjgs9xfF7dZxVSyE6k9NxnUCk+D7nOmp+HqhQY="));
+        assert!(!is_password_hash("$1$too$short"));
+        // digit strings are not Cisco Type 7 secrets
+        assert!(!is_password_hash("123456"));
+        assert!(!is_password_hash("1000"));
+        assert!(!is_password_hash("0000"));
+        assert!(!is_password_hash("0812345678"));
+        assert!(!is_password_hash("094F"));
+    }
+}